@@ -3,33 +3,33 @@
 use std::iter::FusedIterator;
 
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, Storage, U1};
-use ordered_float::NotNan;
-use rand::{prelude::SliceRandom, thread_rng};
+use rand::prelude::SliceRandom;
 use tracing::{instrument, span, trace, Level};
 
-use super::{BrownRobinson, BrownRobinsonRow};
+use super::{BrownRobinson, BrownRobinsonRow, Score};
 
-type T = super::Value;
-
-impl<N: Dim, S: Storage<T, N, N>> BrownRobinson<T, N, S>
+impl<T: Score, N: Dim, S: Storage<T, N, N>> BrownRobinson<T, N, S>
 where
     DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>,
 {
     #[instrument("Selecting strategies", skip_all)]
-    fn next_strategies(&self) -> (usize, usize) {
+    fn next_strategies(&mut self) -> (usize, usize) {
         let Self {
-            a_scores, b_scores, ..
+            a_scores,
+            b_scores,
+            random,
+            ..
         } = self;
 
         let max_a = a_scores
             .iter()
             .copied()
-            .max_by_key(|&value| NotNan::new(value).unwrap())
+            .reduce(|max, value| if value > max { value } else { max })
             .unwrap();
         let min_b = b_scores
             .iter()
             .copied()
-            .min_by_key(|&value| NotNan::new(value).unwrap())
+            .reduce(|min, value| if value < min { value } else { min })
             .unwrap();
 
         trace!(
@@ -37,7 +37,7 @@ where
             a_scores.as_slice(),
             b_scores.as_slice()
         );
-        trace!("max_a = {max_a:.3}, min_b = {min_b:.3}");
+        trace!("max_a = {max_a:.3?}, min_b = {min_b:.3?}");
 
         let a_indices: Vec<_> = a_scores
             .iter()
@@ -52,15 +52,25 @@ where
             .map(|(index, _)| index)
             .collect();
         let (a, b) = (
-            *a_indices.choose(&mut thread_rng()).unwrap(),
-            *b_indices.choose(&mut thread_rng()).unwrap(),
+            *a_indices.choose(random).unwrap(),
+            *b_indices.choose(random).unwrap(),
         );
         trace!("Selected strategies: [{a}][{b}]");
         (a, b)
     }
+
+    /// Performs `n` more steps of the method and returns the resulting accuracy `ε`.
+    ///
+    /// The method can be stepped further after this.
+    pub fn epsilon_after(&mut self, n: usize) -> T::Price {
+        for _ in 0..n {
+            self.next();
+        }
+        self.min_high_price.clone() - self.max_low_price.clone()
+    }
 }
 
-impl<N: Dim, S: Storage<T, N, N>> Iterator for BrownRobinson<T, N, S>
+impl<T: Score, N: Dim, S: Storage<T, N, N>> Iterator for BrownRobinson<T, N, S>
 where
     DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>,
 {
@@ -74,7 +84,10 @@ where
 
         let (high_price, low_price) = if self.k == 1 {
             trace!("Performing initial (no-op) iteration");
-            (self.high_price(), self.low_price())
+            (
+                T::price(self.high_price(), self.k),
+                T::price(self.low_price(), self.k),
+            )
         } else {
             let (a_strategy, b_strategy) = self.next_strategies();
             self.a_strategy = a_strategy;
@@ -83,16 +96,23 @@ where
             self.b_strategy_times_used[b_strategy] += 1;
             self.a_scores += self.game.0.column(b_strategy).transpose();
             self.b_scores += self.game.0.row(a_strategy);
+            self.realized_payoff += self.game.0[(a_strategy, b_strategy)];
 
-            let high_price = self.high_price() / self.k as T;
-            let low_price = self.low_price() / self.k as T;
+            let high_price = T::price(self.high_price(), self.k);
+            let low_price = T::price(self.low_price(), self.k);
 
-            self.min_high_price = self.min_high_price.min(high_price);
-            self.max_low_price = self.max_low_price.max(low_price);
+            if high_price < self.min_high_price {
+                self.min_high_price = high_price.clone();
+            }
+            if low_price > self.max_low_price {
+                self.max_low_price = low_price.clone();
+            }
 
             (high_price, low_price)
         };
-        trace!("Produced prices: ({high_price:.3}; {low_price:.3})");
+        trace!("Produced prices: ({high_price:.3?}; {low_price:.3?})");
+        self.bounds_sum +=
+            T::approximate(&self.min_high_price) + T::approximate(&self.max_low_price);
 
         Some(BrownRobinsonRow {
             iteration: self.k,
@@ -102,12 +122,74 @@ where
             b_score: self.b_scores.clone_owned(),
             high_price,
             low_price,
-            epsilon: self.min_high_price - self.max_low_price,
+            epsilon: self.min_high_price.clone() - self.max_low_price.clone(),
         })
     }
 }
 
-impl<N: Dim, S: Storage<T, N, N>> FusedIterator for BrownRobinson<T, N, S> where
+impl<T: Score, N: Dim, S: Storage<T, N, N>> FusedIterator for BrownRobinson<T, N, S> where
     DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn epsilon_after() {
+        let game = dmatrix![
+            2., -1., 0.;
+            -1., 1., 3.;
+            0., 2., -2.;
+        ];
+        let mut method = BrownRobinson::with_seed(game.clone(), 42);
+        let mut manual = BrownRobinson::with_seed(game, 42);
+
+        let epsilon = method.epsilon_after(100);
+        let last = manual.by_ref().take(100).last().unwrap();
+        assert_eq!(epsilon, last.epsilon);
+        assert_eq!(method.k(), 100);
+
+        assert_eq!(
+            method.next().map(|row| row.epsilon),
+            manual.next().map(|row| row.epsilon),
+        );
+    }
+
+    #[test]
+    fn exact_scores_match_float_scores() {
+        let game = dmatrix![
+            2, -1, 0;
+            -1, 1, 3;
+            0, 2, -2;
+        ];
+        let exact = BrownRobinson::with_seed(game.clone(), 42);
+        let float = BrownRobinson::with_seed(game.map(|value| value as f64), 42);
+
+        for (exact, float) in exact.zip(float).take(200) {
+            assert_eq!(exact.a_strategy, float.a_strategy);
+            assert_eq!(exact.b_strategy, float.b_strategy);
+            let epsilon = *exact.epsilon.numer() as f64 / *exact.epsilon.denom() as f64;
+            assert!((epsilon - float.epsilon).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn exact_scores_do_not_overflow() {
+        let game = dmatrix![
+            2i64, -1, 0;
+            -1, 1, 3;
+            0, 2, -2;
+        ];
+        let mut exact = BrownRobinson::with_seed(game.clone(), 42);
+        let mut float = BrownRobinson::with_seed(game.map(|value| value as f64), 42);
+
+        let epsilon = exact.epsilon_after(2000);
+        let float_epsilon = float.epsilon_after(2000);
+        assert!((i64::approximate(&epsilon) - float_epsilon).abs() < 1e-9);
+        assert!((exact.cesaro_value() - float.cesaro_value()).abs() < 1e-9);
+    }
+}
@@ -1,21 +1,27 @@
 //! Implementation of the Brown-Robinson method.
 
-use game_theory::{ext::ComplexFieldExt, zero_sum::Game};
+use game_theory::{
+    ext::ComplexFieldExt,
+    zero_sum::{Analytic, DGame, Game, MixedSolution, ZeroSumSolver},
+};
 use nalgebra::{
-    allocator::Allocator, ComplexField, DefaultAllocator, Dim, Matrix, OMatrix, OVector, Scalar,
-    SimdPartialOrd, Storage, U1,
+    allocator::Allocator, ComplexField, DMatrix, DVector, DefaultAllocator, Dim, Matrix, OMatrix,
+    OVector, Storage, U1,
 };
-use num_traits::{float::FloatCore, Zero};
+use num_traits::float::FloatCore;
 use ordered_float::NotNan;
-use rand::{thread_rng, Rng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use tracing::{instrument, trace};
 
+pub use score::Score;
+
 mod iter;
+mod score;
 
 // TODO: get rid of the exact used type
 type Value = f64;
 
-pub struct BrownRobinsonRow<T, N: Dim>
+pub struct BrownRobinsonRow<T: Score, N: Dim>
 where
     DefaultAllocator: Allocator<T, U1, N>,
 {
@@ -30,15 +36,15 @@ where
     /// Накопленный выигрыш игрока B
     pub b_score: OMatrix<T, U1, N>,
     /// Верхняя цена игры
-    pub high_price: T,
+    pub high_price: T::Price,
     /// Нижняя цена игры
-    pub low_price: T,
+    pub low_price: T::Price,
     /// ε, разница между минимальной верхней и максиммальной нижней ценами игры
-    pub epsilon: T,
+    pub epsilon: T::Price,
 }
 
 // Итератор по шагам метода
-pub struct BrownRobinson<T, N: Dim, S: Storage<T, N, N>>
+pub struct BrownRobinson<T: Score, N: Dim, S: Storage<T, N, N>>
 where
     DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>,
 {
@@ -47,28 +53,48 @@ where
     b_strategy: usize,
     a_scores: OMatrix<T, U1, N>,
     b_scores: OMatrix<T, U1, N>,
-    min_high_price: T,
-    max_low_price: T,
+    min_high_price: T::Price,
+    max_low_price: T::Price,
     a_strategy_times_used: OMatrix<usize, U1, N>,
     b_strategy_times_used: OMatrix<usize, U1, N>,
+    /// The sum of the payoffs realized by the chosen strategies.
+    realized_payoff: T,
+    /// The sum of the price bounds over all the steps, used for the Cesàro averaging.
+    ///
+    /// The bounds are [approximated](Score::approximate) since the exact prices
+    /// would overflow when summed over many steps.
+    bounds_sum: f64,
     /// The number of the current iteration.
     k: usize,
+    /// The source of randomness used to pick the strategies.
+    random: StdRng,
 }
 
-impl<T: Scalar + Zero + SimdPartialOrd, N: Dim, S: Storage<T, N, N>> BrownRobinson<T, N, S>
+impl<T: Score, N: Dim, S: Storage<T, N, N>> BrownRobinson<T, N, S>
 where
     DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>,
 {
     #[must_use]
-    #[instrument(name = "Init Brown-Robinson method", skip(game_matrix))]
     pub fn new(game_matrix: Matrix<T, N, N, S>) -> Self {
-        let a_strategy = thread_rng().gen_range(0..game_matrix.nrows());
-        let b_strategy = thread_rng().gen_range(0..game_matrix.ncols());
+        Self::with_random(game_matrix, StdRng::from_entropy())
+    }
+
+    /// Creates the method whose random choices are reproducible for the same `seed`.
+    #[must_use]
+    pub fn with_seed(game_matrix: Matrix<T, N, N, S>, seed: u64) -> Self {
+        Self::with_random(game_matrix, StdRng::seed_from_u64(seed))
+    }
+
+    #[instrument(name = "Init Brown-Robinson method", skip(game_matrix, random))]
+    fn with_random(game_matrix: Matrix<T, N, N, S>, mut random: StdRng) -> Self {
+        let a_strategy = random.gen_range(0..game_matrix.nrows());
+        let b_strategy = random.gen_range(0..game_matrix.ncols());
 
         let a_scores = game_matrix.column(a_strategy).transpose();
         let b_scores = game_matrix.row(b_strategy).clone_owned();
-        let min_high_price = a_scores.max();
-        let max_low_price = b_scores.min();
+        let realized_payoff = game_matrix[(a_strategy, b_strategy)];
+        let min_high_price = T::price(a_scores.max(), 1);
+        let max_low_price = T::price(b_scores.min(), 1);
 
         trace!("Using random strategies: [{a_strategy}]={a_scores:.3?} and [{b_strategy}]={b_scores:.3?}");
 
@@ -93,7 +119,10 @@ where
             max_low_price,
             a_strategy_times_used,
             b_strategy_times_used,
+            realized_payoff,
+            bounds_sum: 0.,
             k: 0,
+            random,
         }
     }
 
@@ -127,17 +156,45 @@ where
     }
 
     #[must_use]
-    pub const fn min_max_prices(&self) -> (&T, &T) {
+    pub const fn min_max_prices(&self) -> (&T::Price, &T::Price) {
         (&self.max_low_price, &self.min_high_price)
     }
 
     #[must_use]
     pub fn price_estimation(&self) -> T
     where
-        T: ComplexField,
+        T: Score<Price = T> + ComplexField,
     {
         let (max_low_price, min_high_price) = self.min_max_prices();
-        (max_low_price.clone() + min_high_price.clone()) / T::two()
+        (*max_low_price + *min_high_price) / T::two()
+    }
+
+    /// Computes the Cesàro average of the [price estimations](Self::price_estimation)
+    /// made on all the steps performed so far.
+    ///
+    /// It converges to the value of the game slower than the estimation itself,
+    /// but fluctuates much less from step to step.
+    /// The average is [approximated](Score::approximate) even for the exact scores.
+    #[must_use]
+    pub fn cesaro_value(&self) -> f64 {
+        if self.k == 0 {
+            let (max_low_price, min_high_price) = self.min_max_prices();
+            return (T::approximate(max_low_price) + T::approximate(min_high_price)) / 2.;
+        }
+        self.bounds_sum / (2 * self.k) as f64
+    }
+
+    /// Computes the average payoff actually realized by the strategies chosen so far.
+    ///
+    /// Unlike the [price bounds](Self::min_max_prices), this is the outcome of the play itself,
+    /// which also converges to the value of the game.
+    #[must_use]
+    pub fn average_realized_payoff(&self) -> T
+    where
+        T: ComplexField,
+    {
+        // the initial strategies are played even before the first step
+        self.realized_payoff / T::from_usize(self.k.max(1)).unwrap()
     }
 
     #[must_use]
@@ -158,18 +215,253 @@ where
     }
 
     #[must_use]
-    fn high_price(&self) -> T
-    where
-        T: SimdPartialOrd + Zero,
-    {
+    fn high_price(&self) -> T {
         self.a_scores.max()
     }
 
     #[must_use]
-    fn low_price(&self) -> T
-    where
-        T: SimdPartialOrd + Zero,
-    {
+    fn low_price(&self) -> T {
         self.b_scores.min()
     }
 }
+
+impl<T: Score<Price = Value>, N: Dim, S: Storage<T, N, N>> BrownRobinson<T, N, S>
+where
+    DefaultAllocator: Allocator<usize, U1, N> + Allocator<T, U1, N>,
+{
+    /// Performs `samples` more steps of the method and fits the power law `ε ~ k^(-p)`
+    /// to the observed accuracies by the least squares in log-log scale, returning `p`.
+    ///
+    /// The steps on which `ε` is zero are ignored since the logarithm is undefined for them.
+    /// Returns `NaN` if there are less than two such steps.
+    pub fn convergence_exponent(&mut self, samples: usize) -> Value {
+        let points: Vec<_> = self
+            .take(samples)
+            .filter(|row| row.epsilon > 0.)
+            .map(|row| ((row.iteration as Value).ln(), row.epsilon.ln()))
+            .collect();
+
+        let n = points.len() as Value;
+        let (sum_x, sum_y) = points
+            .iter()
+            .fold((0., 0.), |(sum_x, sum_y), (x, y)| (sum_x + x, sum_y + y));
+        let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+        let (covariance, variance) =
+            points
+                .iter()
+                .fold((0., 0.), |(covariance, variance), (x, y)| {
+                    (
+                        covariance + (x - mean_x) * (y - mean_y),
+                        variance + (x - mean_x) * (x - mean_x),
+                    )
+                });
+
+        -covariance / variance
+    }
+
+    /// Estimates the number of steps after which `ε` drops below the `accuracy`
+    /// by the convergence bound `O(R² / ε²)`, where `R` is the spread of the game's payoffs.
+    ///
+    /// This is a heuristic upper bound rather than a guarantee:
+    /// the method usually converges much faster on small games,
+    /// while the worst-case rate of large games is even slower.
+    #[must_use]
+    pub fn expected_steps_to(&self, accuracy: Value) -> usize {
+        let matrix = &self.game.0;
+        let spread = T::price(matrix.max(), 1) - T::price(matrix.min(), 1);
+        (spread / accuracy).powi(2).ceil() as usize
+    }
+}
+
+impl<N: Dim, S: Storage<Value, N, N>> BrownRobinson<Value, N, S>
+where
+    DefaultAllocator: Allocator<usize, U1, N> + Allocator<Value, U1, N>,
+{
+    /// Performs `samples` more steps of the method recording after each of them
+    /// the distance between the empirical frequencies of the strategies used so far
+    /// and the optimal strategies found [analytically](Analytic).
+    ///
+    /// The distance is the Euclidean norm of the differences of the strategies of both players.
+    /// Returns [`None`] if the game has no analytic solution,
+    /// e.g. if some of the strategies are not used in the equilibrium.
+    pub fn frequency_convergence(&mut self, samples: usize) -> Option<Vec<(usize, Value)>> {
+        let matrix = &self.game.0;
+        let game = DGame::new(DMatrix::from_iterator(
+            matrix.nrows(),
+            matrix.ncols(),
+            matrix.iter().copied(),
+        ));
+        let (_, x, y) = Analytic.solve(game)?;
+
+        let distance = |times_used: &OMatrix<usize, U1, N>, strategy: &DVector<Value>| {
+            let total = times_used.sum() as Value;
+            times_used
+                .iter()
+                .zip(strategy.iter())
+                .map(|(&times, probability)| (times as Value / total - probability).powi(2))
+                .sum::<Value>()
+        };
+        Some(
+            (0..samples)
+                .map_while(|_| {
+                    let BrownRobinsonRow { iteration, .. } = self.next()?;
+                    let squared = distance(&self.a_strategy_times_used, &x)
+                        + distance(&self.b_strategy_times_used, &y);
+                    Some((iteration, squared.sqrt()))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Solver running the Brown-Robinson method until it reaches the given accuracy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrownRobinsonSolver<T> {
+    pub accuracy: T,
+}
+
+impl<T> BrownRobinsonSolver<T> {
+    #[must_use]
+    pub const fn new(accuracy: T) -> Self {
+        Self { accuracy }
+    }
+}
+
+impl ZeroSumSolver<Value> for BrownRobinsonSolver<Value> {
+    fn solve(&mut self, game: DGame<Value>) -> Option<MixedSolution<Value>> {
+        if game.0.is_empty() || !game.0.is_square() {
+            return None;
+        }
+
+        let mut brown_robinson = BrownRobinson::new(game.0);
+        for BrownRobinsonRow { epsilon, .. } in &mut brown_robinson {
+            if epsilon < self.accuracy {
+                break;
+            }
+        }
+
+        let (a_strategy, b_strategy) = brown_robinson.strategies_used();
+        let frequencies = |times_used: DVector<usize>| {
+            let total = times_used.sum() as Value;
+            times_used.map(|times| times as Value / total)
+        };
+        Some((
+            brown_robinson.price_estimation(),
+            frequencies(a_strategy),
+            frequencies(b_strategy),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn average_realized_payoff_approaches_value() {
+        // the value of the game is `0.2`
+        let mut method = BrownRobinson::with_seed(
+            dmatrix![
+                2., -1.;
+                -1., 1.;
+            ],
+            42,
+        );
+        method.epsilon_after(10_000);
+
+        let payoff = method.average_realized_payoff();
+        assert!((payoff - 0.2).abs() < 1e-2, "{payoff}");
+    }
+
+    #[test]
+    fn convergence_exponent_is_plausible() {
+        let mut method = BrownRobinson::with_seed(
+            dmatrix![
+                2., -1., 0.;
+                -1., 1., 3.;
+                0., 2., -2.;
+            ],
+            42,
+        );
+
+        let exponent = method.convergence_exponent(10_000);
+        assert!((0.2..=1.5).contains(&exponent), "{exponent}");
+        assert_eq!(method.k(), 10_000);
+    }
+
+    #[test]
+    fn expected_steps_exceed_actual_ones() {
+        let mut method = BrownRobinson::with_seed(
+            dmatrix![
+                2., -1., 0.;
+                -1., 1., 3.;
+                0., 2., -2.;
+            ],
+            42,
+        );
+        let expected = method.expected_steps_to(0.1);
+        assert_eq!(expected, 2500);
+
+        let steps = method
+            .position(|row| row.epsilon < 0.1)
+            .expect("the method always converges")
+            + 1;
+        assert!(steps <= expected, "{steps} > {expected}");
+    }
+
+    #[test]
+    fn frequencies_approach_equilibrium() {
+        // the optimal strategies of both players are `(0.4, 0.6)`
+        let mut method = BrownRobinson::with_seed(
+            dmatrix![
+                2., -1.;
+                -1., 1.;
+            ],
+            42,
+        );
+        let distances = method.frequency_convergence(10_000).unwrap();
+        assert_eq!(distances.len(), 10_000);
+        assert_eq!(distances.last().unwrap().0, method.k());
+
+        let mean = |distances: &[(usize, f64)]| {
+            distances.iter().map(|(_, distance)| distance).sum::<f64>() / distances.len() as f64
+        };
+        let (early, late) = (mean(&distances[..1_000]), mean(&distances[9_000..]));
+        assert!(late < early, "{late} >= {early}");
+        assert!(distances.last().unwrap().1 < 1e-2);
+    }
+
+    #[test]
+    fn cesaro_value_is_smoother() {
+        // the value of the game is `0.2`
+        let mut method = BrownRobinson::with_seed(
+            dmatrix![
+                2., -1.;
+                -1., 1.;
+            ],
+            42,
+        );
+        method.epsilon_after(1_000);
+
+        let variance = |values: &[f64]| {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / values.len() as f64
+        };
+        let (mut raw, mut cesaro) = (vec![], vec![]);
+        for _ in 0..1_000 {
+            let row = method.next().unwrap();
+            raw.push((row.high_price + row.low_price) / 2.);
+            cesaro.push(method.cesaro_value());
+        }
+
+        let value = method.cesaro_value();
+        assert!((value - 0.2).abs() < 5e-2, "{value}");
+        assert!(variance(&cesaro) < variance(&raw));
+    }
+}
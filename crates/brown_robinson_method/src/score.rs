@@ -0,0 +1,52 @@
+//! Types of the payoffs accumulated by the Brown-Robinson method.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+use nalgebra::{ClosedAdd, Scalar, SimdPartialOrd};
+use num_rational::Ratio;
+use num_traits::Zero;
+
+/// A type of the payoffs accumulated by the method.
+pub trait Score: Scalar + Copy + Zero + PartialOrd + SimdPartialOrd + ClosedAdd {
+    /// The type of the prices of the game, i.e. the scores averaged over the iterations.
+    type Price: Clone
+        + Debug
+        + PartialOrd
+        + Zero
+        + Add<Output = Self::Price>
+        + Sub<Output = Self::Price>;
+
+    /// Computes the price by the score accumulated over `k` iterations.
+    fn price(score: Self, k: usize) -> Self::Price;
+
+    /// Approximates the price by a float, e.g. to accumulate it over many iterations.
+    fn approximate(price: &Self::Price) -> f64;
+}
+
+impl Score for f64 {
+    type Price = f64;
+
+    fn price(score: Self, k: usize) -> Self::Price {
+        score / k as f64
+    }
+
+    fn approximate(price: &Self::Price) -> f64 {
+        *price
+    }
+}
+
+/// Integer scores are accumulated exactly, so are the rational prices.
+impl Score for i64 {
+    type Price = Ratio<i64>;
+
+    fn price(score: Self, k: usize) -> Self::Price {
+        Ratio::new(score, k.try_into().expect("too many iterations"))
+    }
+
+    fn approximate(price: &Self::Price) -> f64 {
+        *price.numer() as f64 / *price.denom() as f64
+    }
+}
@@ -1,18 +1,24 @@
 use std::{collections::VecDeque, iter::FusedIterator, num::NonZeroUsize};
 
-use brown_robinson_method::{BrownRobinson, BrownRobinsonRow};
-use game_theory::zero_sum::Game;
-use nalgebra::{ComplexField, DMatrix, Dyn, VecStorage};
+use brown_robinson_method::BrownRobinsonSolver;
+use game_theory::zero_sum::{Game, ZeroSumSolver};
+use nalgebra::{ComplexField, DMatrix, DVector};
 use tracing::{debug, span, trace, Level};
 
-use crate::{ContinuousConvexConcaveGame, GameSolution};
+use crate::{CoCoCoSummary, ContinuousConvexConcaveGame, GameSolution, MAX_ITERATIONS};
 
-pub struct Iter<'a, T> {
+pub struct Iter<'a, T, S = BrownRobinsonSolver<T>> {
     /// The iterated game
     game: &'a ContinuousConvexConcaveGame<T>,
+    /// The solver of the discretized games which have no saddle point
+    solver: S,
     /// The accuracy defining the end of game
     accuracy: T,
     window_size: NonZeroUsize,
+    /// Whether the value is evaluated at the expected point of the mixed strategies
+    interpolate: bool,
+    /// The maximal error of the values of the discretized games produced by the solver
+    solver_error: T,
 
     deltas: VecDeque<T>,
 
@@ -22,19 +28,23 @@ pub struct Iter<'a, T> {
     sum_delta: T,
 }
 
-impl<'a, T: ComplexField> Iter<'a, T> {
+impl<'a, T: ComplexField, S> Iter<'a, T, S> {
     #[must_use]
     pub(super) fn new(
         game: &'a ContinuousConvexConcaveGame<T>,
         accuracy: T,
         window_size: NonZeroUsize,
+        solver: S,
     ) -> Self {
         // TODO: caller invariant on game properties
         Self {
             game,
+            solver,
             accuracy,
             deltas: VecDeque::with_capacity(window_size.get()),
             window_size,
+            interpolate: false,
+            solver_error: T::zero(),
             n: 1,
             previous_h: None,
             h: T::zero(),
@@ -46,50 +56,102 @@ impl<'a, T: ComplexField> Iter<'a, T> {
     pub const fn n(&self) -> usize {
         self.n
     }
+
+    /// Makes the iterator evaluate `H` at the expected point of the mixed strategies
+    /// instead of using the value of the discretized game,
+    /// which smooths the staircase caused by the grid.
+    #[must_use]
+    pub fn interpolated(self) -> Self {
+        Self {
+            interpolate: true,
+            ..self
+        }
+    }
+
+    /// Specifies the maximal error of the values of the discretized games produced by the solver
+    /// which is accounted for in the [guaranteed accuracy](Iter::guaranteed_accuracy).
+    ///
+    /// The solvers are considered exact by default.
+    #[must_use]
+    pub fn with_solver_error(self, solver_error: T) -> Self {
+        Self {
+            solver_error,
+            ..self
+        }
+    }
 }
 
-impl Iter<'_, f64> {
+impl<S> Iter<'_, f64, S> {
+    /// Estimates the error of the game's value introduced by the discretization on the current grid.
+    ///
+    /// The nearest grid point is at most `1/(2n)` away from the continuous optimum by each axis,
+    /// and since the first-order derivatives vanish at the saddle point,
+    /// the value deviates by at most `(|h_xx| + |h_yy|) / 2 * (1/(2n))^2`.
+    #[must_use]
+    pub fn discretization_error_bound(&self) -> f64 {
+        let half_step = 0.5 / self.n as f64;
+        (self.game.h_xx().abs() + self.game.h_yy().abs()) / 2. * half_step * half_step
+    }
+
+    /// Computes the bound of the error of the last produced value of the game.
+    ///
+    /// The stopping rule of the method, i.e. the sum of the changes of the value
+    /// within the window not exceeding the accuracy, only bounds the drift of the value:
+    /// any of the values within the window differs from the last one by at most the sum.
+    /// It does not bound the error of the value itself as the value may keep drifting slowly,
+    /// yet it is stricter than bounding the maximal change within the window.
+    ///
+    /// The error is instead bounded by the [discretization error](Self::discretization_error_bound)
+    /// of the current grid along with the [error of the solver](Self::with_solver_error).
+    /// This does not hold for the [interpolated](Self::interpolated) values.
+    #[must_use]
+    pub fn guaranteed_accuracy(&self) -> f64 {
+        self.discretization_error_bound() + self.solver_error
+    }
+
     /// Creates game matrix for the current iteration.
     ///
     /// # Panics
     ///
     /// If the resulting matrix cannot be created due to it being too big.
     fn current_game(&self) -> Game<DMatrix<f64>> {
-        let dimension = self.n + 1;
-        // check that we don't overflow
-        dimension
-            .checked_mul(dimension)
-            .expect("the resulting matrix is too big");
-
-        let divisor = self.n as f64;
-        let data = (0..dimension)
-            .flat_map(|j| (0..dimension).map(move |i| (i, j)))
-            .map(|(i, j)| self.game.compute(i as f64 / divisor, j as f64 / divisor))
-            .collect();
-
-        Game::new(DMatrix::from_vec_storage(VecStorage::new(
-            Dyn(dimension),
-            Dyn(dimension),
-            data,
-        )))
+        self.game
+            .discretize(NonZeroUsize::new(self.n).expect("n is always positive"))
     }
 }
 
+/// Computes the expected position on the grid of the given resolution under the mixed `strategy`.
+///
+/// Unlike the most used grid point, this interpolates between the grid points
+/// so that the optimums lying between them are estimated more accurately.
+fn expected_position(strategy: &DVector<f64>, divisor: f64) -> f64 {
+    let total = strategy.sum();
+    strategy
+        .iter()
+        .enumerate()
+        .map(|(point, probability)| point as f64 * probability)
+        .sum::<f64>()
+        / total
+        / divisor
+}
+
 // TODO: generify on value type
-impl Iterator for Iter<'_, f64> {
+impl<S: ZeroSumSolver<f64>> Iterator for Iter<'_, f64, S> {
     type Item = GameSolution<f64>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.n = self
-            .n
-            .checked_add(1)
-            .expect("too many iterations have happened");
-
-        let span = span!(Level::DEBUG, "CoCoCo-method iteration", n = self.n);
-        let _enter = span.enter();
         trace!(delta = self.sum_delta, "Checking conditions");
 
-        if self.deltas.is_empty() || self.sum_delta > self.accuracy {
+        if !self.is_converged() {
+            // the resolution is only increased when the step is performed
+            // so that it corresponds to the last produced value
+            self.n = self
+                .n
+                .checked_add(1)
+                .expect("too many iterations have happened");
+
+            let span = span!(Level::DEBUG, "CoCoCo-method iteration", n = self.n);
+            let _enter = span.enter();
             debug!("Performing iterative step");
 
             let game = self.current_game();
@@ -119,18 +181,16 @@ impl Iterator for Iter<'_, f64> {
                 let span = span!(Level::TRACE, "Lo!=Hi");
                 let _enter = span.enter();
 
-                trace!("Performing Brown-Robinson iteration");
-                let mut brown_robinson = BrownRobinson::new(game.0);
-                for BrownRobinsonRow { epsilon, .. } in &mut brown_robinson {
-                    if epsilon < self.accuracy {
-                        break;
-                    }
-                }
-                let h = brown_robinson.price_estimation();
-                let (a_strategy, b_strategy) = brown_robinson.strategies_used();
-                let x = a_strategy.imax() as f64 / divisor;
-                let y = b_strategy.imax() as f64 / divisor;
-                debug!("Brown-Robinson method completed: x={x:.03}, y={y:.03}, h={h:.03}");
+                trace!("Solving the game in mixed strategies");
+                let (h, a_strategy, b_strategy) = self.solver.solve(game)?;
+                let x = expected_position(&a_strategy, divisor);
+                let y = expected_position(&b_strategy, divisor);
+                let h = if self.interpolate {
+                    self.game.compute(x, y)
+                } else {
+                    h
+                };
+                debug!("Mixed strategies found: x={x:.03}, y={y:.03}, h={h:.03}");
                 (h, x, y)
             };
             self.h = h;
@@ -153,4 +213,120 @@ impl Iterator for Iter<'_, f64> {
     }
 }
 
-impl FusedIterator for Iter<'_, f64> {}
+impl<S: ZeroSumSolver<f64>> FusedIterator for Iter<'_, f64, S> {}
+
+impl<S: ZeroSumSolver<f64>> Iter<'_, f64, S> {
+    /// Checks if the values have changed by no more than the accuracy over the window,
+    /// after which no more iterations are performed.
+    fn is_converged(&self) -> bool {
+        !self.deltas.is_empty() && self.sum_delta <= self.accuracy
+    }
+
+    /// Consumes the iterator performing at most [`MAX_ITERATIONS`] iterations
+    /// and summarizes the last solution along with the details of the process.
+    ///
+    /// Returns [`None`] if no solution has been found.
+    #[must_use]
+    pub fn summarize(mut self) -> Option<CoCoCoSummary<f64>> {
+        let (iterations, solution) = self.by_ref().take(MAX_ITERATIONS).enumerate().last()?;
+        let iterations = iterations + 1;
+
+        Some(CoCoCoSummary {
+            solution,
+            iterations,
+            sum_delta: self.sum_delta,
+            // the iterations may also stop early if the solver fails
+            capped: iterations == MAX_ITERATIONS && !self.is_converged(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_theory::zero_sum::LinearProgramming;
+
+    use super::*;
+
+    #[test]
+    fn expected_position_is_closer_than_mode() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { x, y, .. } = game.solve_analytically();
+
+        let (mut expected_error, mut mode_error) = (0., 0.);
+        let mut iter = game.iter_with_solver(0., NonZeroUsize::new(1).unwrap(), LinearProgramming);
+        for n in 2..=20 {
+            iter.n = n;
+            let grid = iter.current_game();
+            if grid.lowest_price().1 == grid.highest_price().1 {
+                continue;
+            }
+
+            let (_, a_strategy, b_strategy) = LinearProgramming.solve(grid).unwrap();
+            let divisor = n as f64;
+            expected_error += (expected_position(&a_strategy, divisor) - x).abs()
+                + (expected_position(&b_strategy, divisor) - y).abs();
+            mode_error += (a_strategy.imax() as f64 / divisor - x).abs()
+                + (b_strategy.imax() as f64 / divisor - y).abs();
+        }
+
+        assert!(expected_error < mode_error);
+    }
+
+    #[test]
+    fn discretization_error_bound_shrinks() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let mut iter = game.iter(0.1, NonZeroUsize::new(1).unwrap());
+
+        let mut previous = iter.discretization_error_bound();
+        for n in 2..=10 {
+            iter.n = n;
+            let bound = iter.discretization_error_bound();
+            assert!(bound < previous);
+            previous = bound;
+        }
+        // (6 + 3) / 2 * (1/20)^2
+        assert!((previous - 0.01125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn guaranteed_accuracy_bounds_error() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { h, .. } = game.solve_analytically();
+
+        for accuracy in [0.1, 0.01, 0.001] {
+            let mut iter =
+                game.iter_with_solver(accuracy, NonZeroUsize::new(5).unwrap(), LinearProgramming);
+            let last = iter.by_ref().last().unwrap();
+            let bound = iter.guaranteed_accuracy();
+            assert!(
+                (last.h - h).abs() <= bound,
+                "{} > {bound}",
+                (last.h - h).abs()
+            );
+        }
+
+        let iter = game.iter(0.01, NonZeroUsize::new(5).unwrap());
+        assert!(
+            (iter.guaranteed_accuracy() - iter.discretization_error_bound() - 0.005).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn interpolation_smooths_convergence() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { h, .. } = game.solve_analytically();
+        let window_size = NonZeroUsize::new(1).unwrap();
+
+        // the total error over the first iterations
+        let error = |iter: Iter<'_, f64, LinearProgramming>| -> f64 {
+            iter.take(30).map(|solution| (solution.h - h).abs()).sum()
+        };
+        let grid = error(game.iter_with_solver(0., window_size, LinearProgramming));
+        let interpolated = error(
+            game.iter_with_solver(0., window_size, LinearProgramming)
+                .interpolated(),
+        );
+
+        assert!(interpolated < grid, "{interpolated} >= {grid}");
+    }
+}
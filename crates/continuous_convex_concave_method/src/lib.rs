@@ -1,17 +1,26 @@
 use std::{
     fmt::{self, Display, Formatter},
     num::NonZeroUsize,
+    ops::RangeInclusive,
     write,
 };
 
+use brown_robinson_method::BrownRobinsonSolver;
 use formula::{XFormula, YFormula};
-use game_theory::ext::ComplexFieldExt;
+use game_theory::{
+    ext::ComplexFieldExt,
+    zero_sum::{DGame, Game},
+};
 use iter::Iter;
-use nalgebra::ComplexField;
+use nalgebra::{ComplexField, DMatrix, Dyn, RealField, VecStorage};
 
 mod formula;
 mod iter;
 
+/// The maximal number of iterations performed by the iterative methods,
+/// i.e. the [grid method](Iter::summarize) and the [golden-section search](ContinuousConvexConcaveGame::solve_golden_section).
+pub const MAX_ITERATIONS: usize = 1000;
+
 /// A zero-sum game in a form:
 ///
 /// ```latex
@@ -64,6 +73,17 @@ impl<T: ComplexField> ContinuousConvexConcaveGame<T> {
         b.clone() + b.clone()
     }
 
+    /// Computes the determinant of the Hessian matrix of the kernel,
+    /// whose mixed second-order partial derivative is `c`.
+    #[must_use]
+    pub fn hessian_determinant(&self) -> T {
+        let Self {
+            coefficients: [_, _, c, _, _],
+        } = self;
+
+        self.h_xx() * self.h_yy() - c.clone() * c.clone()
+    }
+
     /// Computes the partial derivative by `x`.
     #[must_use]
     pub fn h_x(&self, x: T, y: T) -> T {
@@ -95,6 +115,22 @@ impl<T: ComplexField> ContinuousConvexConcaveGame<T> {
         (XFormula { a, c: c.clone(), d }, YFormula { b, c, e })
     }
 
+    /// Computes the best reply of player A to the given `y`,
+    /// i.e. the `x` maximizing `H(x,y)` at the vertex of the parabola.
+    #[must_use]
+    pub fn best_reply_x(&self, y: T) -> T {
+        let (x_formula, _) = self.x_y_formulas();
+        x_formula.compute(y)
+    }
+
+    /// Computes the best reply of player B to the given `x`,
+    /// i.e. the `y` minimizing `H(x,y)` at the vertex of the parabola.
+    #[must_use]
+    pub fn best_reply_y(&self, x: T) -> T {
+        let (_, y_formula) = self.x_y_formulas();
+        y_formula.compute(x)
+    }
+
     /// Solves this formula producing the values of `x` and `y`
     /// and the corresponding `H(x,y)`.
     #[must_use]
@@ -127,9 +163,298 @@ impl<T: ComplexField> ContinuousConvexConcaveGame<T> {
         GameSolution { x, y, h }
     }
 
+    /// Checks if the kernel is separable, i.e. has no `xy` term,
+    /// so that `x` and `y` are optimized independently.
+    #[must_use]
+    pub fn is_separable(&self) -> bool {
+        let Self {
+            coefficients: [_, _, c, _, _],
+        } = self;
+
+        c.is_zero()
+    }
+
+    /// Solves the [separable](Self::is_separable) game optimizing `x` and `y` independently
+    /// producing their values and the corresponding `H(x,y)`.
+    ///
+    /// Returns [`None`] if the game is not separable.
+    #[must_use]
+    pub fn solve_separable(&self) -> Option<GameSolution<T>> {
+        if !self.is_separable() {
+            return None;
+        }
+        let Self {
+            coefficients: [a, b, _, d, e],
+        } = self;
+
+        let x = -d.clone() / (T::two() * a.clone());
+        let y = -e.clone() / (T::two() * b.clone());
+        let h = self.compute(x.clone(), y.clone());
+
+        Some(GameSolution { x, y, h })
+    }
+
+    /// Iterates over the solutions of the discretized games
+    /// solving them via the Brown-Robinson method when there is no saddle point.
     #[must_use]
     pub fn iter(&self, accuracy: T, window_size: NonZeroUsize) -> Iter<T> {
-        Iter::new(self, accuracy, window_size)
+        // the value is estimated as the middle of the bounds differing by at most the accuracy
+        let solver_error = accuracy.clone() / T::two();
+        Iter::new(
+            self,
+            accuracy.clone(),
+            window_size,
+            BrownRobinsonSolver::new(accuracy),
+        )
+        .with_solver_error(solver_error)
+    }
+
+    /// Iterates over the solutions of the discretized games
+    /// solving them via the given `solver` when there is no saddle point.
+    ///
+    /// The iteration stops early if the solver fails.
+    #[must_use]
+    pub fn iter_with_solver<S>(
+        &self,
+        accuracy: T,
+        window_size: NonZeroUsize,
+        solver: S,
+    ) -> Iter<T, S> {
+        Iter::new(self, accuracy, window_size, solver)
+    }
+}
+
+impl<T: RealField> ContinuousConvexConcaveGame<T> {
+    /// Classifies the critical point of the kernel by the second-order partial derivatives.
+    ///
+    /// The kernel of a proper game has a [saddle point](CriticalPointKind::SaddlePoint).
+    #[must_use]
+    pub fn classify_critical_point(&self) -> CriticalPointKind {
+        let determinant = self.hessian_determinant();
+        if determinant < T::zero() {
+            CriticalPointKind::SaddlePoint
+        } else if determinant.is_zero() {
+            CriticalPointKind::Degenerate
+        } else if self.h_xx() < T::zero() {
+            CriticalPointKind::Maximum
+        } else {
+            CriticalPointKind::Minimum
+        }
+    }
+
+    /// Solves the game restricted to the given ranges of `x` and `y`
+    /// producing the saddle point and the corresponding `H(x,y)`.
+    ///
+    /// If the [interior solution](Self::solve_analytically) lies outside the domain,
+    /// the violating variables are clamped to the boundary
+    /// and the remaining one is re-optimized via its [formula](Self::x_y_formulas),
+    /// choosing the combination in which each variable is the best response to the other.
+    ///
+    /// # Panics
+    ///
+    /// If the game is not convex-concave, i.e. `h_xx` is not negative or `h_yy` is not positive,
+    /// in which case there may be no saddle point.
+    #[must_use]
+    pub fn solve_with_domain(
+        &self,
+        x_range: RangeInclusive<T>,
+        y_range: RangeInclusive<T>,
+    ) -> GameSolution<T> {
+        let (x_formula, y_formula) = self.x_y_formulas();
+        let interior = self.solve_analytically();
+        let bounds = |range: &RangeInclusive<T>| {
+            [None, Some(range.start().clone()), Some(range.end().clone())]
+        };
+
+        for fixed_x in bounds(&x_range) {
+            for fixed_y in bounds(&y_range) {
+                let (x, y) = match (fixed_x.clone(), fixed_y.clone()) {
+                    (None, None) => (interior.x.clone(), interior.y.clone()),
+                    (Some(x), None) => (x.clone(), y_formula.compute(x)),
+                    (None, Some(y)) => (x_formula.compute(y.clone()), y),
+                    (Some(x), Some(y)) => (x, y),
+                };
+                if is_best_response(
+                    &x,
+                    fixed_x.as_ref(),
+                    &x_formula.compute(y.clone()),
+                    &x_range,
+                ) && is_best_response(
+                    &y,
+                    fixed_y.as_ref(),
+                    &y_formula.compute(x.clone()),
+                    &y_range,
+                ) {
+                    let h = self.compute(x.clone(), y.clone());
+                    return GameSolution { x, y, h };
+                }
+            }
+        }
+
+        panic!("the game should be convex-concave to have a saddle point")
+    }
+
+    /// Solves the game on `[0, 1]²` by iterating the best replies of the players,
+    /// each found numerically by the golden-section search, until they change by at most `tolerance`.
+    ///
+    /// Unlike the grid method, which solves a matrix game of `O(n²)` size on each iteration,
+    /// each best reply only takes `O(log(1 / tolerance))` evaluations of the kernel.
+    /// This is preferable when the kernel is known to be convex-concave
+    /// and the best replies are contractive, i.e. `c² < 4|ab|`, e.g. if the kernel is separable;
+    /// otherwise the iteration may fail to converge and stops after [`MAX_ITERATIONS`].
+    #[must_use]
+    pub fn solve_golden_section(&self, tolerance: T) -> GameSolution<T> {
+        let (mut x, mut y) = (T::zero(), T::zero());
+        for _ in 0..MAX_ITERATIONS {
+            let next_x = golden_section_max(|x| self.compute(x, y.clone()), tolerance.clone());
+            let next_y =
+                golden_section_max(|y| -self.compute(next_x.clone(), y), tolerance.clone());
+            let change = (next_x.clone() - x).abs() + (next_y.clone() - y).abs();
+            (x, y) = (next_x, next_y);
+            if change <= tolerance {
+                break;
+            }
+        }
+
+        let h = self.compute(x.clone(), y.clone());
+        GameSolution { x, y, h }
+    }
+}
+
+/// Finds the maximum of the unimodal function `f` on `[0, 1]` by the golden-section search
+/// narrowing the interval down to the `tolerance`.
+fn golden_section_max<T: RealField>(f: impl Fn(T) -> T, tolerance: T) -> T {
+    let ratio = (T::from_subset(&5.).sqrt() - T::one()) / T::two();
+    let (mut low, mut high) = (T::zero(), T::one());
+    let mut left = high.clone() - ratio.clone() * (high.clone() - low.clone());
+    let mut right = low.clone() + ratio.clone() * (high.clone() - low.clone());
+    let (mut f_left, mut f_right) = (f(left.clone()), f(right.clone()));
+    while high.clone() - low.clone() > tolerance {
+        // only one new point is computed on each step as the ratio is preserved
+        if f_left < f_right {
+            low = left;
+            left = right.clone();
+            f_left = f_right;
+            right = low.clone() + ratio.clone() * (high.clone() - low.clone());
+            f_right = f(right.clone());
+        } else {
+            high = right;
+            right = left.clone();
+            f_right = f_left;
+            left = high.clone() - ratio.clone() * (high.clone() - low.clone());
+            f_left = f(left.clone());
+        }
+    }
+
+    (low + high) / T::two()
+}
+
+/// Checks if the `value`, possibly `fixed` to the boundary of the `range`,
+/// is the best response whose unrestricted value is `best`.
+fn is_best_response<T: RealField>(
+    value: &T,
+    fixed: Option<&T>,
+    best: &T,
+    range: &RangeInclusive<T>,
+) -> bool {
+    match fixed {
+        None => range.contains(value),
+        Some(bound) if bound == range.start() => best <= range.start(),
+        Some(_) => best >= range.end(),
+    }
+}
+
+impl<T: ComplexField + Display> ContinuousConvexConcaveGame<T> {
+    /// Generates the LaTeX derivation of the [analytic solution](Self::solve_analytically)
+    /// with the coefficients of the game substituted.
+    ///
+    /// The derivation consists of the kernel, its partial derivatives set to zero,
+    /// the system of `x` and `y` expressed via each other, and the optimal `x*`, `y*` and `H*`.
+    #[must_use]
+    pub fn solution_latex(&self) -> String {
+        let Self {
+            coefficients: [a, b, c, d, e],
+        } = self;
+        let [a, b, c, d, e] = [a, b, c, d, e].map(latex_operand);
+        let GameSolution { x, y, h } = self.solve_analytically();
+
+        [
+            format!(r"\[ H(x, y) = {a} x^2 + {b} y^2 + {c} xy + {d} x + {e} y \]"),
+            format!(
+                r"\[ \begin{{cases}} \frac{{\partial H}}{{\partial x}} = 2 \cdot {a} x + {c} y + {d} = 0 \\ \frac{{\partial H}}{{\partial y}} = 2 \cdot {b} y + {c} x + {e} = 0 \end{{cases}} \]"
+            ),
+            format!(
+                r"\[ \begin{{cases}} x = \frac{{-{c} y - {d}}}{{2 \cdot {a}}} \\ y = \frac{{-{c} x - {e}}}{{2 \cdot {b}}} \end{{cases}} \]"
+            ),
+            format!(
+                r"\[ x^* = \frac{{{c} \cdot {e} - 2 \cdot {b} \cdot {d}}}{{4 \cdot {a} \cdot {b} - {c}^2}} = {x} \]"
+            ),
+            format!(r"\[ y^* = \frac{{-{c} x^* - {e}}}{{2 \cdot {b}}} = {y} \]"),
+            format!(r"\[ H^* = H(x^*, y^*) = {h} \]"),
+        ]
+        .join("\n")
+    }
+}
+
+/// Formats the value to be used as an operand in LaTeX wrapping the negative ones in parentheses.
+fn latex_operand(value: &impl Display) -> String {
+    let value = value.to_string();
+    if value.starts_with('-') {
+        format!(r"\left({value}\right)")
+    } else {
+        value
+    }
+}
+
+impl ContinuousConvexConcaveGame<f64> {
+    /// Creates the matrix game on the uniform grid of `resolution + 1` points by each axis.
+    ///
+    /// # Panics
+    ///
+    /// If the resulting matrix cannot be created due to it being too big.
+    #[must_use]
+    pub fn discretize(&self, resolution: NonZeroUsize) -> DGame<f64> {
+        Game::new(self.sample_grid(resolution))
+    }
+
+    /// Samples the values of the kernel function on the uniform grid
+    /// of `resolution + 1` points by each axis of `[0, 1]²`,
+    /// e.g. to plot its surface.
+    ///
+    /// The rows correspond to the values of `x` and the columns to the values of `y`.
+    ///
+    /// # Panics
+    ///
+    /// If the resulting matrix cannot be created due to it being too big.
+    #[must_use]
+    pub fn sample_grid(&self, resolution: NonZeroUsize) -> DMatrix<f64> {
+        let n = resolution.get();
+        let dimension = n.checked_add(1).expect("the resolution is too big");
+        // check that we don't overflow
+        dimension
+            .checked_mul(dimension)
+            .expect("the resulting matrix is too big");
+
+        let divisor = n as f64;
+        let data = (0..dimension)
+            .flat_map(|j| (0..dimension).map(move |i| (i, j)))
+            .map(|(i, j)| self.compute(i as f64 / divisor, j as f64 / divisor))
+            .collect();
+
+        DMatrix::from_vec_storage(VecStorage::new(Dyn(dimension), Dyn(dimension), data))
+    }
+
+    /// Runs the [iterative method](Self::iter) to the end
+    /// [summarizing](Iter::summarize) the final solution along with the details of the process.
+    ///
+    /// Returns [`None`] if no solution has been found.
+    #[must_use]
+    pub fn solve_iteratively(
+        &self,
+        accuracy: f64,
+        window_size: NonZeroUsize,
+    ) -> Option<CoCoCoSummary<f64>> {
+        self.iter(accuracy, window_size).summarize()
     }
 }
 
@@ -142,9 +467,225 @@ impl<T: Display> Display for ContinuousConvexConcaveGame<T> {
     }
 }
 
+/// The result of [running](ContinuousConvexConcaveGame::solve_iteratively) the iterative method.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoCoCoSummary<T> {
+    /// The last found solution.
+    pub solution: GameSolution<T>,
+    /// The number of performed iterations.
+    pub iterations: usize,
+    /// The sum of the value changes within the last window.
+    pub sum_delta: T,
+    /// Whether the iterations were stopped by [`MAX_ITERATIONS`] before reaching the accuracy.
+    pub capped: bool,
+}
+
+/// The kind of the [critical point](ContinuousConvexConcaveGame::classify_critical_point) of the kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CriticalPointKind {
+    /// The Hessian is indefinite.
+    SaddlePoint,
+    /// The Hessian is negative definite.
+    Maximum,
+    /// The Hessian is positive definite.
+    Minimum,
+    /// The Hessian is singular so the second-order analysis is inconclusive.
+    Degenerate,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GameSolution<T> {
     pub x: T,
     pub y: T,
     pub h: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use game_theory::zero_sum::LinearProgramming;
+
+    use super::*;
+
+    #[test]
+    fn iterate_with_linear_programming() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { h, .. } = game.solve_analytically();
+        let window_size = NonZeroUsize::new(10).unwrap();
+
+        let brown_robinson = game.iter(0.01, window_size).last().unwrap();
+        let linear_programming = game
+            .iter_with_solver(0.01, window_size, LinearProgramming)
+            .last()
+            .unwrap();
+
+        assert!((linear_programming.h - h).abs() < 1e-3);
+        assert!((linear_programming.h - brown_robinson.h).abs() < 1e-2);
+    }
+
+    #[test]
+    fn discretize() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { h, .. } = game.solve_analytically();
+
+        let grid = game.discretize(NonZeroUsize::new(2).unwrap());
+        assert_eq!(grid.0.shape(), (3, 3));
+        assert_eq!(grid.0[(2, 1)], game.compute(1., 0.5));
+
+        let (value, _, _) = game
+            .discretize(NonZeroUsize::new(10).unwrap())
+            .solve_linear_programming()
+            .unwrap();
+        // (6 + 3) / 2 * (1/20)^2
+        assert!((value - h).abs() < 0.01125);
+    }
+
+    #[test]
+    fn best_replies_converge() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let solution = game.solve_analytically();
+
+        // the equilibrium is the fixed point of the mutual best replies
+        assert!((game.best_reply_x(solution.y) - solution.x).abs() < 1e-12);
+        assert!((game.best_reply_y(solution.x) - solution.y).abs() < 1e-12);
+
+        let (mut x, mut y) = (0., 0.);
+        for _ in 0..100 {
+            x = game.best_reply_x(y);
+            y = game.best_reply_y(x);
+        }
+        assert!((x - solution.x).abs() < 1e-9);
+        assert!((y - solution.y).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solve_separable() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 0., 1.2, -2.4]);
+        assert!(game.is_separable());
+
+        let GameSolution { x, y, h } = game.solve_separable().unwrap();
+        assert!((x - 0.2).abs() < 1e-12);
+        assert!((y - 0.8).abs() < 1e-12);
+        let general = game.solve_analytically();
+        assert!((x - general.x).abs() < 1e-12);
+        assert!((y - general.y).abs() < 1e-12);
+        assert!((h - general.h).abs() < 1e-12);
+
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        assert!(!game.is_separable());
+        assert_eq!(game.solve_separable(), None);
+    }
+
+    #[test]
+    fn sample_grid() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let grid = game.sample_grid(NonZeroUsize::new(50).unwrap());
+
+        assert_eq!(grid.shape(), (51, 51));
+        assert_eq!(grid[(0, 0)], game.compute(0., 0.));
+        assert_eq!(grid[(50, 0)], game.compute(1., 0.));
+        assert_eq!(grid[(0, 50)], game.compute(0., 1.));
+        assert_eq!(grid[(50, 50)], game.compute(1., 1.));
+        assert_eq!(grid[(10, 20)], game.compute(0.2, 0.4));
+    }
+
+    #[test]
+    fn solve_iteratively() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let window_size = NonZeroUsize::new(10).unwrap();
+
+        let summary = game.solve_iteratively(0.01, window_size).unwrap();
+        assert!(summary.iterations > 0);
+        assert!(!summary.capped);
+        assert!(summary.sum_delta <= 0.01);
+
+        // unlike the Brown-Robinson method, linear programming is deterministic
+        let iter = || game.iter_with_solver(0.01, window_size, LinearProgramming);
+        let summary = iter().summarize().unwrap();
+        assert_eq!(summary.solution, iter().last().unwrap());
+        assert_eq!(summary.iterations, iter().count());
+    }
+
+    #[test]
+    fn classify_critical_point() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        // -6 * 3 - 3.6^2
+        assert!((game.hessian_determinant() + 30.96).abs() < 1e-9);
+        assert_eq!(
+            game.classify_critical_point(),
+            CriticalPointKind::SaddlePoint
+        );
+
+        let game = ContinuousConvexConcaveGame::new([3., 1.5, 1., -0.36, -2.88]);
+        assert_eq!(game.hessian_determinant(), 17.);
+        assert_eq!(game.classify_critical_point(), CriticalPointKind::Minimum);
+
+        let game = ContinuousConvexConcaveGame::new([-3., -1.5, 1., -0.36, -2.88]);
+        assert_eq!(game.classify_critical_point(), CriticalPointKind::Maximum);
+
+        let game = ContinuousConvexConcaveGame::new([1., 1., 2., -0.36, -2.88]);
+        assert_eq!(game.hessian_determinant(), 0.);
+        assert_eq!(
+            game.classify_critical_point(),
+            CriticalPointKind::Degenerate
+        );
+    }
+
+    #[test]
+    fn solve_with_domain() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { x, y, h } = game.solve_with_domain(0. ..=1., 0. ..=1.);
+        let interior = game.solve_analytically();
+        assert!((x - interior.x).abs() < 1e-9);
+        assert!((y - interior.y).abs() < 1e-9);
+        assert!((h - interior.h).abs() < 1e-9);
+
+        // the interior solution is `(2, 1)`
+        let game = ContinuousConvexConcaveGame::new([-1., 1., 1., 3., -4.]);
+        let interior = game.solve_analytically();
+        assert!((interior.x - 2.).abs() < 1e-9);
+        assert_eq!(
+            game.solve_with_domain(0. ..=1., 0. ..=1.),
+            GameSolution {
+                x: 1.,
+                y: 1.,
+                h: 0.
+            }
+        );
+
+        // `x` is pushed to the upper bound making `y` hit the lower one
+        let game = ContinuousConvexConcaveGame::new([-1., 1., 4., 6., -1.]);
+        assert_eq!(
+            game.solve_with_domain(0. ..=1., 0. ..=1.),
+            GameSolution {
+                x: 1.,
+                y: 0.,
+                h: 5.
+            }
+        );
+    }
+
+    #[test]
+    fn solve_golden_section() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let GameSolution { x, y, h } = game.solve_golden_section(1e-9);
+        let analytic = game.solve_analytically();
+        assert!((x - analytic.x).abs() < 1e-6, "{x}");
+        assert!((y - analytic.y).abs() < 1e-6, "{y}");
+        assert!((h - analytic.h).abs() < 1e-6, "{h}");
+    }
+
+    #[test]
+    fn solution_latex() {
+        let game = ContinuousConvexConcaveGame::new([-3., 1.5, 3.6, -0.36, -2.88]);
+        let latex = game.solution_latex();
+
+        assert_eq!(latex.lines().count(), 6);
+        assert!(latex.contains(r"\frac{-3.6 y - \left(-0.36\right)}{2 \cdot \left(-3\right)}"));
+        assert!(latex.contains(r"\left(-2.88\right)"));
+        assert!(latex.contains(r"2 \cdot 1.5 y"));
+
+        let GameSolution { x, h, .. } = game.solve_analytically();
+        assert!(latex.contains(&format!("= {x} ")));
+        assert!(latex.ends_with(&format!("= {h} \\]")));
+    }
+}
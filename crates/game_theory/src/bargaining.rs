@@ -0,0 +1,114 @@
+//! [Bargaining problems][1] of two players.
+//!
+//! The feasible sets are given by finite sets of payoff pairs, e.g. the Pareto-efficient outcomes
+//! of a [bimatrix game](crate::non_cooperative::BiMatrixGame).
+//!
+//! [1]: https://en.wikipedia.org/wiki/Cooperative_bargaining
+
+use std::cmp::Ordering;
+
+/// Finds the [Nash bargaining solution][1]
+/// which maximizes the product of the players' gains over the `disagreement` point.
+///
+/// Only the points which are not worse than the disagreement point for both players are considered,
+/// if there are none, [`None`] is returned.
+///
+/// [1]: https://en.wikipedia.org/wiki/Cooperative_bargaining#Nash_bargaining_solution
+pub fn nash_bargaining_solution(
+    feasible: &[(f64, f64)],
+    disagreement: (f64, f64),
+) -> Option<(f64, f64)> {
+    let (d1, d2) = disagreement;
+    feasible
+        .iter()
+        .copied()
+        .filter(|&(u1, u2)| u1 >= d1 && u2 >= d2)
+        .max_by(|&(l1, l2), &(r1, r2)| {
+            ((l1 - d1) * (l2 - d2))
+                .partial_cmp(&((r1 - d1) * (r2 - d2)))
+                .unwrap_or(Ordering::Equal)
+        })
+}
+
+/// Finds the [Kalai-Smorodinsky bargaining solution][1]
+/// which lies on the line from the `disagreement` point to the ideal point
+/// formed by the best achievable payoffs of each of the players.
+///
+/// Since the feasible set is finite, the point maximizing the least of the players' gains
+/// relative to their ideal gains is chosen.
+/// Only the points which are not worse than the disagreement point for both players are considered,
+/// if there are none, [`None`] is returned.
+///
+/// [1]: https://en.wikipedia.org/wiki/Kalai%E2%80%93Smorodinsky_bargaining_solution
+pub fn kalai_smorodinsky(feasible: &[(f64, f64)], disagreement: (f64, f64)) -> Option<(f64, f64)> {
+    let (d1, d2) = disagreement;
+    let rational: Vec<_> = feasible
+        .iter()
+        .copied()
+        .filter(|&(u1, u2)| u1 >= d1 && u2 >= d2)
+        .collect();
+    let (ideal1, ideal2) = rational
+        .iter()
+        .fold((d1, d2), |(ideal1, ideal2), &(u1, u2)| {
+            (ideal1.max(u1), ideal2.max(u2))
+        });
+
+    // a player who cannot gain anything is fully satisfied by any point
+    let relative_gain = |u: f64, d: f64, ideal: f64| {
+        if ideal > d {
+            (u - d) / (ideal - d)
+        } else {
+            1.
+        }
+    };
+    rational.into_iter().max_by(|&(l1, l2), &(r1, r2)| {
+        let left = relative_gain(l1, d1, ideal1).min(relative_gain(l2, d2, ideal2));
+        let right = relative_gain(r1, d1, ideal1).min(relative_gain(r2, d2, ideal2));
+        left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+    use crate::non_cooperative::{BiMatrixGame, Pair};
+
+    #[test]
+    fn nash_bargaining() {
+        let feasible = [(0., 4.), (2., 3.), (3., 1.), (4., 0.)];
+        assert_eq!(
+            nash_bargaining_solution(&feasible, (0., 0.)),
+            Some((2., 3.))
+        );
+        assert_eq!(
+            nash_bargaining_solution(&feasible, (2.5, 0.)),
+            Some((3., 1.))
+        );
+        assert_eq!(nash_bargaining_solution(&feasible, (5., 5.)), None);
+    }
+
+    #[test]
+    fn kalai_smorodinsky_differs_from_nash() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(2., 10.), Pair(6., 7.);
+            Pair(8., 5.5), Pair(10., 0.);
+        ]);
+        let feasible: Vec<_> = game
+            .pareto_efficients()
+            .map(|strategy| {
+                let Pair(a, b) = *strategy.wins;
+                (a, b)
+            })
+            .collect();
+        assert_eq!(feasible.len(), 4);
+
+        assert_eq!(
+            nash_bargaining_solution(&feasible, (0., 0.)),
+            Some((8., 5.5))
+        );
+        assert_eq!(kalai_smorodinsky(&feasible, (0., 0.)), Some((6., 7.)));
+        assert_eq!(kalai_smorodinsky(&feasible, (20., 0.)), None);
+    }
+}
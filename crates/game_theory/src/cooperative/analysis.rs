@@ -0,0 +1,222 @@
+use super::{factorial, Coalition, CooperativeGame};
+
+/// Rationality analysis of the [Shapley value](CooperativeGame::x) of a game.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ShapleyAnalysis {
+    /// The Shapley value, i.e. the share of each player.
+    pub shapley_value: Vec<f64>,
+    /// The difference between the sum of the shares and the value of the grand coalition.
+    pub group_rationality_residual: f64,
+    /// Whether each player gets no less than they would get alone.
+    pub individually_rational: Vec<bool>,
+}
+
+impl ShapleyAnalysis {
+    /// Checks if the shares sum up to the value of the grand coalition up to the given tolerance.
+    pub fn is_group_rational(&self, tolerance: f64) -> bool {
+        self.group_rationality_residual.abs() <= tolerance
+    }
+}
+
+impl CooperativeGame<f64> {
+    /// Computes the Shapley value and checks its group and individual rationality.
+    pub fn analyze_shapley(&self) -> ShapleyAnalysis {
+        let shapley_value: Vec<_> = self.x().collect();
+        let group_rationality_residual = shapley_value.iter().sum::<f64>() - self.v_i();
+        let individually_rational = shapley_value
+            .iter()
+            .zip(self.singular_coalitions())
+            .map(|(&x, i)| x >= *self.v(i))
+            .collect();
+
+        ShapleyAnalysis {
+            shapley_value,
+            group_rationality_residual,
+            individually_rational,
+        }
+    }
+
+    /// Ranks the players by their [Shapley value](Self::x) from the most to the least influential.
+    ///
+    /// The players are numbered from `1` and the ones with equal values keep their order.
+    pub fn shapley_ranking(&self) -> Vec<(u8, f64)> {
+        let mut ranking: Vec<_> = (1..).zip(self.x()).collect();
+        ranking.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+        ranking
+    }
+
+    /// Computes the Shapley value of the sub-game of the given `players`,
+    /// i.e. the game in which only the coalitions of these players are possible.
+    ///
+    /// The shares are listed in the order of `players`.
+    ///
+    /// # Panics
+    ///
+    /// If `players` is empty, contains repeated players or the ones exceeding the game.
+    pub fn shapley_for_subset(&self, players: &[u8]) -> Vec<f64> {
+        assert!(!players.is_empty(), "there should be at least one player");
+        let masks: Vec<_> = players
+            .iter()
+            .map(|&player| Coalition(self.player_mask(player) as usize))
+            .collect();
+        for (index, mask) in masks.iter().enumerate() {
+            assert!(
+                masks[..index].iter().all(|other| !other.overlaps(*mask)),
+                "player={} occurs in players={players:?} more than once",
+                players[index]
+            );
+        }
+
+        // the first of the `players` takes the highest bit of the sub-game's coalitions
+        let characteristic_function = (0..1usize << players.len())
+            .map(|sub_coalition| {
+                let coalition = masks
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .filter(|(bit, _)| sub_coalition & (1 << bit) != 0)
+                    .fold(Coalition::empty(), |coalition, (_, &mask)| coalition | mask);
+                *self.v(coalition)
+            })
+            .collect();
+
+        Self::new(characteristic_function)
+            .expect("there are 2^n coalitions")
+            .x()
+            .collect()
+    }
+
+    /// Computes the Shapley interaction index of the players `i` and `j`,
+    /// i.e. the average synergy of their joint presence in the coalitions.
+    ///
+    /// The synergy within a coalition `S` of the other players is the discrete second derivative
+    /// `v(S ∪ {i, j}) - v(S ∪ {i}) - v(S ∪ {j}) + v(S)`,
+    /// which is weighted by `|S|! (n - |S| - 2)! / (n - 1)!` as in the [Shapley value](Self::x).
+    /// The index is positive if the players complement each other
+    /// and negative if they substitute each other.
+    ///
+    /// # Panics
+    ///
+    /// If `i` and `j` are the same player or exceed the game.
+    pub fn interaction_index(&self, i: u8, j: u8) -> f64 {
+        assert_ne!(i, j, "the interaction is defined for distinct players");
+        let n = self.player_count().get();
+        let (i, j) = (
+            Coalition(self.player_mask(i) as usize),
+            Coalition(self.player_mask(j) as usize),
+        );
+        let pair = i | j;
+
+        self.coalitions()
+            .filter(|s| !s.overlaps(pair))
+            .map(|s| {
+                let weight = (factorial(s.n_members()) * factorial(n - s.n_members() - 2)) as f64
+                    / factorial(n - 1) as f64;
+                weight * (self.v(s | pair) - self.v(s | i) - self.v(s | j) + self.v(s))
+            })
+            .sum()
+    }
+
+    /// Splits the value of the grand coalition equally among the players.
+    ///
+    /// This is the baseline for the comparison with the other allocations
+    /// such as the [Shapley value](Self::x).
+    pub fn egalitarian_allocation(&self) -> Vec<f64> {
+        let n = self.player_count().get();
+        vec![self.v_i() / f64::from(n); n as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_lab_example() {
+        let game = CooperativeGame::new(vec![
+            0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.,
+        ])
+        .unwrap();
+        let analysis = game.analyze_shapley();
+
+        assert_eq!(analysis.shapley_value.len(), 4);
+        assert!(analysis.is_group_rational(1e-9));
+        assert_eq!(analysis.individually_rational, vec![true; 4]);
+    }
+
+    #[test]
+    fn shapley_ranking_of_lab_example() {
+        let game = CooperativeGame::new(vec![
+            0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.,
+        ])
+        .unwrap();
+        let ranking = game.shapley_ranking();
+
+        let players: Vec<_> = ranking.iter().map(|&(player, _)| player).collect();
+        assert_eq!(players, vec![1, 2, 3, 4]);
+        assert!((ranking[0].1 - 71. / 12.).abs() < 1e-9);
+        assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let game = CooperativeGame::new(vec![0., 2., 1., 6.]).unwrap();
+        assert_eq!(game.shapley_ranking(), vec![(2, 3.5), (1, 2.5)]);
+    }
+
+    #[test]
+    fn shapley_for_subset() {
+        let game = CooperativeGame::new(vec![
+            0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.,
+        ])
+        .unwrap();
+
+        let full: Vec<_> = game.x().collect();
+        for (subset, full) in game.shapley_for_subset(&[0, 1, 2, 3]).into_iter().zip(full) {
+            assert!((subset - full).abs() < 1e-9, "{subset} != {full}");
+        }
+
+        // `v({1}) = 4`, `v({4}) = 1` and `v({1, 4}) = 7`
+        assert_eq!(game.shapley_for_subset(&[3, 0]), vec![2., 5.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shapley_for_subset_rejects_repeated_players() {
+        let game = CooperativeGame::new(vec![0., 1., 1., 3.]).unwrap();
+        let _ = game.shapley_for_subset(&[1, 1]);
+    }
+
+    #[test]
+    fn interaction_index() {
+        // only the first two players together create the value
+        let game = CooperativeGame::new(vec![0., 0., 0., 0., 0., 0., 1., 1.]).unwrap();
+        assert!((game.interaction_index(0, 1) - 1.).abs() < 1e-12);
+        assert!((game.interaction_index(1, 0) - 1.).abs() < 1e-12);
+        assert_eq!(game.interaction_index(0, 2), 0.);
+
+        // the players of an additive game do not interact
+        let game = CooperativeGame::new(vec![0., 1., 2., 3., 4., 5., 6., 7.]).unwrap();
+        assert_eq!(game.interaction_index(0, 1), 0.);
+    }
+
+    #[test]
+    fn egalitarian_allocation_is_efficient() {
+        let game = CooperativeGame::new(vec![
+            0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.,
+        ])
+        .unwrap();
+        let allocation = game.egalitarian_allocation();
+
+        assert_eq!(allocation, vec![3.; 4]);
+        assert!((allocation.iter().sum::<f64>() - game.v_i()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn detects_group_irrationality() {
+        // the empty coalition has a non-zero value, so the shares do not sum up to `v(I)`
+        let game = CooperativeGame::new(vec![1., 1., 1., 3.]).unwrap();
+        let analysis = game.analyze_shapley();
+
+        assert!((analysis.group_rationality_residual + 1.).abs() < 1e-9);
+        assert!(!analysis.is_group_rational(1e-9));
+    }
+}
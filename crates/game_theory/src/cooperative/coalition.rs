@@ -3,13 +3,13 @@ use std::{
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign},
 };
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Coalition(pub(super) usize);
 
 impl Display for Coalition {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let Self(bits) = self;
-        write!(f, "{bits:b}")
+        fmt::Binary::fmt(bits, f)
     }
 }
 
@@ -0,0 +1,98 @@
+use nalgebra::DMatrix;
+
+use super::CooperativeGame;
+use crate::{
+    non_cooperative::{NMatrixGame, Profiles},
+    zero_sum::DGame,
+};
+
+impl CooperativeGame<f64> {
+    /// Creates the game whose characteristic function is the maximin value of the coalitions
+    /// in the given non-cooperative game.
+    ///
+    /// The value `v(S)` is the total payoff which the members of `S` can guarantee
+    /// in the [zero-sum game](DGame::solve_linear_programming) against the complement `I \ S`,
+    /// where the joint strategy profiles of both sides are their pure strategies.
+    /// The grand coalition gets the maximal total payoff over all the profiles.
+    ///
+    /// Note that the zero-sum games grow as the products of the numbers of strategies.
+    pub fn from_normal_form<T: Copy + Into<f64>>(game: &NMatrixGame<T>) -> Self {
+        let players = game.players();
+        let strategies = game.strategies();
+        let total_payoff = |profile: &[usize], members: &[usize]| -> f64 {
+            members
+                .iter()
+                .map(|&player| (*game.payoff(profile, player)).into())
+                .sum()
+        };
+
+        let everyone: Vec<_> = (0..players).collect();
+        let grand_coalition = (1usize << players) - 1;
+        let characteristic_function = (0..=grand_coalition)
+            .map(|coalition| {
+                if coalition == 0 {
+                    return 0.;
+                }
+                if coalition == grand_coalition {
+                    return Profiles::new(strategies)
+                        .map(|profile| total_payoff(&profile, &everyone))
+                        .fold(f64::NEG_INFINITY, f64::max);
+                }
+
+                // the first player is the most significant bit of the coalition
+                let (members, opponents): (Vec<_>, Vec<_>) = (0..players)
+                    .partition(|&player| coalition & (1 << (players - 1 - player)) != 0);
+                let joint_strategies = |side: &[usize]| -> Vec<_> {
+                    let side_strategies: Vec<_> =
+                        side.iter().map(|&player| strategies[player]).collect();
+                    Profiles::new(&side_strategies).collect()
+                };
+                let (rows, columns) = (joint_strategies(&members), joint_strategies(&opponents));
+
+                let mut profile = vec![0; players];
+                let matrix = DMatrix::from_fn(rows.len(), columns.len(), |row, column| {
+                    for (&player, &strategy) in members.iter().zip(&rows[row]) {
+                        profile[player] = strategy;
+                    }
+                    for (&player, &strategy) in opponents.iter().zip(&columns[column]) {
+                        profile[player] = strategy;
+                    }
+                    total_payoff(&profile, &members)
+                });
+                let (value, _, _) = DGame::new(matrix)
+                    .solve_linear_programming()
+                    .expect("each side should have a strategy");
+                value
+            })
+            .collect();
+
+        Self::new(characteristic_function)
+            .expect("there should be a value for each of the coalitions")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximin_characteristic_function() {
+        // players 1 and 2 want to coordinate while player 3 wants to differ from player 1,
+        // so that a lone player guarantees half of the coordination by mixing
+        let game = NMatrixGame::from_fn(vec![2, 2, 2], |profile| {
+            let coordinated = if profile[0] == profile[1] { 1. } else { 0. };
+            let differs = if profile[2] == profile[0] { -2. } else { 2. };
+            vec![coordinated, coordinated, differs]
+        })
+        .unwrap();
+
+        let expected = [0., 0., 0.5, 0.5, 0.5, 2.5, 2., 4.];
+        let cooperative = CooperativeGame::from_normal_form(&game);
+        for (coalition, expected) in cooperative.coalitions().zip(expected) {
+            assert!(
+                (cooperative.v(coalition) - expected).abs() < 1e-9,
+                "v({coalition}) should be {expected}"
+            );
+        }
+    }
+}
@@ -1,9 +1,22 @@
+mod analysis;
 mod coalition;
+mod maximin;
+mod owen;
+mod stability;
 
-use std::{num::NonZeroU8, ops::Add};
+use std::{
+    num::NonZeroU8,
+    ops::{Add, Sub},
+};
 
-use coalition::Coalition;
+pub use analysis::ShapleyAnalysis;
+pub use coalition::Coalition;
+use nalgebra::DMatrix;
+use num_traits::Zero;
+pub use owen::InvalidCoalitionStructure;
+use rand::{distributions::uniform::SampleRange, Rng};
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CooperativeGame<T>(Box<[T]>);
 
 impl<T> CooperativeGame<T> {
@@ -37,6 +50,24 @@ impl<T> CooperativeGame<T> {
         self.0.last().expect("the vector is known to not be empty")
     }
 
+    /// Gets the value of the empty coalition.
+    pub fn v_empty(&self) -> &T {
+        self.v(Coalition::empty())
+    }
+
+    /// Checks that the value of the empty coalition is zero
+    /// which is assumed by most of the solution concepts.
+    pub fn is_zero_normalized(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.v_empty().is_zero()
+    }
+
+    fn grand_coalition(&self) -> Coalition {
+        Coalition(self.0.len() - 1)
+    }
+
     pub fn singular_coalitions(&self) -> impl Iterator<Item = Coalition> + '_ {
         (0..self.player_count().get()).map(|player| Coalition(self.player_mask(player) as usize))
     }
@@ -55,7 +86,61 @@ impl<T> CooperativeGame<T> {
     }
 }
 
+impl<T: Sub<Output = T> + Clone> CooperativeGame<T> {
+    /// Creates the dual game defined as `v*(S) = v(I) - v(I \ S)`.
+    pub fn dual(&self) -> Self {
+        let grand_coalition = self.grand_coalition();
+        Self(
+            self.coalitions()
+                .map(|s| self.v_i().clone() - self.v(grand_coalition - s).clone())
+                .collect(),
+        )
+    }
+}
+
+impl<T: Add<Output = T> + Clone> CooperativeGame<T> {
+    /// Creates the game of the players of both games acting independently,
+    /// i.e. the value of a coalition is the sum of the values of its parts in each of the games.
+    ///
+    /// The players of this game come first, followed by the players of the `other` one.
+    /// The Shapley value of the composition is the concatenation of the games' Shapley values.
+    pub fn compose(&self, other: &Self) -> Self {
+        // the members of this game take the higher bits of the composed coalitions
+        Self(
+            self.coalitions()
+                .flat_map(|s| {
+                    other
+                        .coalitions()
+                        .map(move |t| self.v(s).clone() + other.v(t).clone())
+                })
+                .collect(),
+        )
+    }
+}
+
 impl<T: PartialOrd + Add<Output = T> + Clone> CooperativeGame<T> {
+    /// Creates the minimal super-additive game whose characteristic function
+    /// is not less than this game's one.
+    ///
+    /// The value of each coalition is the best total value
+    /// which can be achieved by splitting it into disjoint sub-coalitions.
+    pub fn super_additive_closure(&self) -> Self {
+        let mut closure = self.0.clone();
+        // all sub-coalitions of a coalition have smaller indices
+        for coalition in 1..closure.len() {
+            let mut part = (coalition - 1) & coalition;
+            while part != 0 {
+                let split = closure[part].clone() + closure[coalition & !part].clone();
+                if split > closure[coalition] {
+                    closure[coalition] = split;
+                }
+                part = (part - 1) & coalition;
+            }
+        }
+
+        Self(closure)
+    }
+
     pub fn is_super_additive(&self) -> bool {
         use itertools::Itertools;
 
@@ -69,6 +154,36 @@ impl<T: PartialOrd + Add<Output = T> + Clone> CooperativeGame<T> {
             })
     }
 
+    /// Finds the pairs of disjoint coalitions `(S, T)` for which `v(S ∪ T) < v(S) + v(T)`,
+    /// i.e. which break the [super-additivity](Self::is_super_additive).
+    ///
+    /// Each pair is listed once with `S <= T`.
+    pub fn super_additivity_violations(&self) -> Vec<(Coalition, Coalition)> {
+        use itertools::Itertools;
+
+        self.coalitions()
+            .cartesian_product(self.coalitions())
+            .filter(|(s, t)| s <= t && !s.overlaps(*t))
+            .filter(|(s, t)| self.v(*s | *t) < &(self.v(*s).clone() + self.v(*t).clone()))
+            .collect()
+    }
+
+    /// Finds the pairs of coalitions `(S, T)` for which `v(S ∪ T) + v(S ∩ T) < v(S) + v(T)`,
+    /// i.e. which break the [convexity](Self::is_convex).
+    ///
+    /// Each pair is listed once with `S < T`.
+    pub fn convexity_violations(&self) -> Vec<(Coalition, Coalition)> {
+        use itertools::Itertools;
+
+        self.coalitions()
+            .tuple_combinations()
+            .filter(|(s, t)| {
+                let left = self.v(*s | *t).clone() + self.v(*s & *t).clone();
+                left < self.v(*s).clone() + self.v(*t).clone()
+            })
+            .collect()
+    }
+
     pub fn is_convex(&self) -> bool
     where
         T: core::fmt::Display,
@@ -87,7 +202,7 @@ impl<T: PartialOrd + Add<Output = T> + Clone> CooperativeGame<T> {
     }
 }
 
-impl CooperativeGame<u8> {
+impl<T: Copy + Into<f64>> CooperativeGame<T> {
     pub fn x(&self) -> impl Iterator<Item = f64> + '_ {
         let n = self.player_count().get();
         let n_factorial: f64 = (1..=n as u64).product::<u64>() as f64;
@@ -96,15 +211,14 @@ impl CooperativeGame<u8> {
             let player_mask = self.player_mask(player) as usize;
             let i = Coalition(player_mask);
 
-            let product: u64 = self
+            let product: f64 = self
                 .x_i(player)
                 .map(|s| {
-                    factorial(s.n_members() - 1)
-                        * factorial(n - s.n_members())
-                        * (self.v(s) - self.v(s - i)) as u64
+                    (factorial(s.n_members() - 1) * factorial(n - s.n_members())) as f64
+                        * ((*self.v(s)).into() - (*self.v(s - i)).into())
                 })
                 .sum();
-            product as f64 / n_factorial
+            product / n_factorial
         })
     }
 
@@ -115,13 +229,207 @@ impl CooperativeGame<u8> {
     }
 }
 
+impl CooperativeGame<f64> {
+    /// Generates a random super-additive game with a zero-valued empty coalition.
+    ///
+    /// # Panics
+    ///
+    /// If there are too many players to represent all the coalitions.
+    pub fn random_superadditive(
+        mut random: impl Rng,
+        players: NonZeroU8,
+        range: impl SampleRange<f64> + Clone,
+    ) -> Self {
+        let coalitions = 1usize
+            .checked_shl(players.get().into())
+            .filter(|&coalitions| coalitions != 0)
+            .unwrap_or_else(|| panic!("players={players} is too big"));
+
+        let characteristic_function = (0..coalitions)
+            .map(|coalition| {
+                if coalition == 0 {
+                    0.
+                } else {
+                    random.gen_range(range.clone())
+                }
+            })
+            .collect();
+
+        Self(characteristic_function).super_additive_closure()
+    }
+
+    /// Generates a random convex game with a zero-valued empty coalition.
+    ///
+    /// The game is built from its [Harsanyi dividends][1] `d(T)`, i.e. `v(S) = Σ d(T)` over `T ⊆ S`:
+    /// the players alone get the values from the `range`, while the dividends of the larger coalitions
+    /// are the absolute values of the ones from the `range`. As a sum of an additive game
+    /// and of the unanimity games with non-negative weights, the game is convex,
+    /// so its core is not empty and contains the Shapley value.
+    ///
+    /// # Panics
+    ///
+    /// If there are too many players to represent all the coalitions.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Harsanyi_dividend
+    pub fn random_convex(
+        mut random: impl Rng,
+        players: NonZeroU8,
+        range: impl SampleRange<f64> + Clone,
+    ) -> Self {
+        let coalitions = 1usize
+            .checked_shl(players.get().into())
+            .filter(|&coalitions| coalitions != 0)
+            .unwrap_or_else(|| panic!("players={players} is too big"));
+
+        let mut characteristic_function: Box<[f64]> = (0..coalitions)
+            .map(|coalition: usize| match coalition.count_ones() {
+                0 => 0.,
+                1 => random.gen_range(range.clone()),
+                _ => random.gen_range(range.clone()).abs(),
+            })
+            .collect();
+        // sum up the dividends of the sub-coalitions one player at a time
+        for player in 0..players.get() {
+            let mask = 1 << player;
+            for coalition in 0..coalitions {
+                if coalition & mask != 0 {
+                    characteristic_function[coalition] += characteristic_function[coalition ^ mask];
+                }
+            }
+        }
+
+        Self(characteristic_function)
+    }
+
+    /// Computes the marginal contribution of each player
+    /// when the players join the grand coalition in the given `order`.
+    ///
+    /// The Shapley value is the average of these vectors over all the orders.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not a permutation of all the players.
+    pub fn marginal_vector(&self, order: &[u8]) -> Vec<f64> {
+        let n = self.player_count().get();
+        assert_eq!(
+            order.len(),
+            n as usize,
+            "order={order:?} should contain all {n} players"
+        );
+
+        let mut marginals = vec![f64::NAN; n as usize];
+        let mut coalition = Coalition::empty();
+        for &player in order {
+            let i = Coalition(self.player_mask(player) as usize);
+            assert!(
+                !coalition.overlaps(i),
+                "player={player} occurs in order={order:?} more than once"
+            );
+
+            let previous = *self.v(coalition);
+            coalition |= i;
+            marginals[player as usize] = self.v(coalition) - previous;
+        }
+
+        marginals
+    }
+
+    /// Iterates over the coalitions containing the `player`
+    /// along with the player's marginal contribution to each of them.
+    pub fn marginal_contributions(
+        &self,
+        player: u8,
+    ) -> impl Iterator<Item = (Coalition, f64)> + '_ {
+        let i = Coalition(self.player_mask(player) as usize);
+        self.x_i(player)
+            .map(move |s| (s, self.v(s) - self.v(s - i)))
+    }
+
+    /// Computes the marginal contributions of all the players to all the coalitions as a matrix
+    /// whose rows are indexed by the coalitions and the columns by the players.
+    ///
+    /// The entry `(S, i)` is `v(S ∪ {i}) - v(S)` if the player `i` is not in `S` and zero otherwise,
+    /// which can be visualized by [highlighting](crate::highlight::WithHighlighting).
+    pub fn marginal_matrix(&self) -> DMatrix<f64> {
+        let players = self.player_count().get();
+        DMatrix::from_fn(self.0.len(), players as usize, |coalition, player| {
+            let coalition = Coalition(coalition);
+            let i = Coalition(self.player_mask(player as u8) as usize);
+            if coalition.overlaps(i) {
+                0.
+            } else {
+                self.v(coalition | i) - self.v(coalition)
+            }
+        })
+    }
+
+    /// Finds the coalition to which the `player` contributes the most.
+    pub fn max_contribution_coalition(&self, player: u8) -> (Coalition, f64) {
+        self.marginal_contributions(player)
+            .reduce(|max, contribution| {
+                if contribution.1 > max.1 {
+                    contribution
+                } else {
+                    max
+                }
+            })
+            .expect("each player is a member of the grand coalition")
+    }
+
+    /// Checks if the `player` is a dummy, i.e. contributes nothing to any coalition.
+    pub fn is_dummy(&self, player: u8) -> bool {
+        self.marginal_contributions(player)
+            .all(|(_, contribution)| contribution == 0.)
+    }
+
+    /// Checks if the game is additive (inessential) up to the given tolerance,
+    /// i.e. `v(S) = Σ v({i})` over the members of each coalition `S`,
+    /// so that the players gain nothing from the cooperation.
+    pub fn is_additive(&self, tolerance: f64) -> bool {
+        let singletons: Vec<_> = self.singular_coalitions().collect();
+        self.coalitions().all(|coalition| {
+            let sum: f64 = singletons
+                .iter()
+                .filter(|i| coalition.overlaps(**i))
+                .map(|&i| self.v(i))
+                .sum();
+            (self.v(coalition) - sum).abs() <= tolerance
+        })
+    }
+
+    /// Decomposes the [additive](Self::is_additive) game into the values of the players alone,
+    /// which are then also its Shapley value.
+    ///
+    /// Returns [`None`] if the game is not additive up to the given tolerance.
+    pub fn additive_decomposition(&self, tolerance: f64) -> Option<Vec<f64>> {
+        self.is_additive(tolerance)
+            .then(|| self.singular_coalitions().map(|i| *self.v(i)).collect())
+    }
+
+    /// Finds the minimal carrier of the game, i.e. the players who are not [dummies](Self::is_dummy).
+    ///
+    /// The value of any coalition `S` equals the value of its intersection with the carrier `T`,
+    /// i.e. `v(S) = v(S ∩ T)`, so the other players can be removed from the game.
+    pub fn carrier(&self) -> Vec<u8> {
+        (0..self.player_count().get())
+            .filter(|&player| !self.is_dummy(player))
+            .collect()
+    }
+}
+
 fn factorial(n: u8) -> u64 {
     (1..=n as u64).product()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::cooperative::CooperativeGame;
+    use std::num::NonZeroU8;
+
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use crate::cooperative::{Coalition, CooperativeGame};
 
     #[test]
     fn test_player_mask() {
@@ -138,4 +446,174 @@ mod tests {
         assert_eq!(super::factorial(2), 2);
         assert_eq!(super::factorial(3), 6);
     }
+
+    #[test]
+    fn marginal_vectors_average_to_shapley_value() {
+        let game = CooperativeGame::new(vec![
+            0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.,
+        ])
+        .unwrap();
+
+        let mut sum = vec![0.; 4];
+        let mut orders = 0;
+        for order in (0..4).permutations(4) {
+            for (sum, marginal) in sum.iter_mut().zip(game.marginal_vector(&order)) {
+                *sum += marginal;
+            }
+            orders += 1;
+        }
+
+        for (average, x) in sum.into_iter().map(|sum| sum / orders as f64).zip(game.x()) {
+            assert!((average - x).abs() < 1e-9, "{average} != {x}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn marginal_vector_rejects_repeated_players() {
+        let game = CooperativeGame::new(vec![0., 1., 1., 3.]).unwrap();
+        let _ = game.marginal_vector(&[0, 0]);
+    }
+
+    #[test]
+    fn dual_of_dual_is_original() {
+        let game = CooperativeGame::new(vec![0, 1, 1, 2, 1, 2, 3, 6]).unwrap();
+        let dual = game.dual();
+        assert_eq!(
+            dual,
+            CooperativeGame::new(vec![0, 3, 4, 5, 4, 5, 5, 6]).unwrap()
+        );
+        assert_eq!(dual.dual(), game);
+    }
+
+    #[test]
+    fn nonzero_empty_coalition() {
+        let game = CooperativeGame::new(vec![1, 2, 2, 5]).unwrap();
+        assert_eq!(*game.v_empty(), 1);
+        assert_eq!(*game.v_i(), 5);
+        assert!(!game.is_zero_normalized());
+
+        assert!(CooperativeGame::new(vec![0, 2, 2, 5])
+            .unwrap()
+            .is_zero_normalized());
+    }
+
+    #[test]
+    fn super_additive_closure() {
+        let game = CooperativeGame::new(vec![0, 2, 3, 4, 1, 2, 5, 6]).unwrap();
+        assert!(!game.is_super_additive());
+
+        let closure = game.super_additive_closure();
+        assert_eq!(
+            closure,
+            CooperativeGame::new(vec![0, 2, 3, 5, 1, 3, 5, 7]).unwrap()
+        );
+        assert!(closure.is_super_additive());
+    }
+
+    #[test]
+    fn shapley_value_of_composition_is_concatenation() {
+        let first = CooperativeGame::new(vec![0., 1., 2., 6.]).unwrap();
+        let second = CooperativeGame::new(vec![0., 1., 1., 2., 1., 2., 3., 6.]).unwrap();
+
+        let composition = first.compose(&second);
+        assert_eq!(composition.player_count().get(), 5);
+        assert_eq!(*composition.v(Coalition(0b10_011)), 2. + 2.);
+
+        let expected: Vec<_> = first.x().chain(second.x()).collect();
+        for (actual, expected) in composition.x().zip(expected) {
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn random_superadditive() {
+        let game = CooperativeGame::random_superadditive(
+            ChaCha20Rng::seed_from_u64(42),
+            NonZeroU8::new(4).unwrap(),
+            -10. ..=10.,
+        );
+        assert_eq!(game.player_count().get(), 4);
+        assert_eq!(*game.v_empty(), 0.);
+        assert!(game.is_super_additive());
+    }
+
+    #[test]
+    fn marginal_matrix() {
+        let game = CooperativeGame::new(vec![0., 1., 1., 2., 1., 2., 3., 6.]).unwrap();
+        let marginals = game.marginal_matrix();
+        assert_eq!(marginals.shape(), (8, 3));
+
+        // the first player joining `{2, 3}` and the third one joining `{1, 2}`
+        assert_eq!(marginals[(0b011, 0)], 6. - 2.);
+        assert_eq!(marginals[(0b110, 2)], 6. - 3.);
+        assert_eq!(marginals[(0b000, 1)], 1.);
+        // the player is already in the coalition
+        assert_eq!(marginals[(0b101, 0)], 0.);
+        assert!(marginals.row(7).iter().all(|&marginal| marginal == 0.));
+    }
+
+    #[test]
+    fn additive_game() {
+        let game = CooperativeGame::new(vec![0., 3., 2., 5., 1., 4., 3., 6.]).unwrap();
+        assert!(game.is_additive(1e-9));
+        let decomposition = game.additive_decomposition(1e-9).unwrap();
+        assert_eq!(decomposition, [1., 2., 3.]);
+        for (x, value) in game.x().zip(decomposition) {
+            assert!((x - value).abs() < 1e-9, "{x} != {value}");
+        }
+
+        let essential = CooperativeGame::new(vec![0., 1., 1., 3.]).unwrap();
+        assert!(!essential.is_additive(1e-9));
+        assert_eq!(essential.additive_decomposition(1e-9), None);
+    }
+
+    #[test]
+    fn random_convex() {
+        let mut random = ChaCha20Rng::seed_from_u64(42);
+        for players in 1..=5 {
+            let game = CooperativeGame::random_convex(
+                &mut random,
+                NonZeroU8::new(players).unwrap(),
+                -10. ..=10.,
+            );
+            assert_eq!(game.player_count().get(), players);
+            assert_eq!(*game.v_empty(), 0.);
+            assert!(game.convexity_violations().is_empty());
+            assert!(game.is_super_additive());
+        }
+    }
+
+    #[test]
+    fn carrier_excludes_dummy() {
+        // the second player contributes nothing
+        let game = CooperativeGame::new(vec![0., 1., 0., 1., 2., 4., 2., 4.]).unwrap();
+        assert!(game.is_dummy(1));
+        assert!(!game.is_dummy(0));
+        assert_eq!(game.carrier(), vec![0, 2]);
+
+        let carrier = game.carrier();
+        for s in game.coalitions() {
+            let intersection = carrier
+                .iter()
+                .map(|&player| Coalition(game.player_mask(player) as usize))
+                .fold(Coalition::empty(), |coalition, mask| coalition | (s & mask));
+            assert_eq!(game.v(s), game.v(intersection));
+        }
+    }
+
+    #[test]
+    fn max_contribution_coalition() {
+        let game = CooperativeGame::new(vec![0., 1., 1., 2., 1., 2., 3., 6.]).unwrap();
+        assert_eq!(
+            game.marginal_contributions(0).collect::<Vec<_>>(),
+            vec![
+                (Coalition(0b100), 1.),
+                (Coalition(0b101), 1.),
+                (Coalition(0b110), 2.),
+                (Coalition(0b111), 4.),
+            ]
+        );
+        assert_eq!(game.max_contribution_coalition(0), (Coalition(0b111), 4.));
+    }
 }
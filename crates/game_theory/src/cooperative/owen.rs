@@ -0,0 +1,164 @@
+use super::{factorial, Coalition, CooperativeGame};
+
+/// Error returned when the coalition structure is not a partition of the players.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidCoalitionStructure {
+    #[error("the structure contains the empty coalition")]
+    EmptyCoalition,
+    #[error("coalitions {0} and {1} overlap")]
+    Overlapping(Coalition, Coalition),
+    #[error("players {0} are not in any coalition")]
+    Uncovered(Coalition),
+    #[error("coalition {0} contains players outside the game")]
+    OutOfGame(Coalition),
+}
+
+impl CooperativeGame<f64> {
+    /// Computes the [Owen value][1] of the game with the given coalition `structure`,
+    /// i.e. the partition of the players into the pre-formed unions.
+    ///
+    /// This is the two-level Shapley value: the unions get their Shapley values
+    /// in the game between them, and each of these is then split among the members of the union
+    /// by their contributions to the coalitions formed with the preceding unions.
+    /// The value coincides with the [Shapley value](Self::x)
+    /// if each player forms its own union or if all the players form a single one.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Owen_value
+    pub fn owen_value(
+        &self,
+        structure: &[Coalition],
+    ) -> Result<Vec<f64>, InvalidCoalitionStructure> {
+        let grand_coalition = self.grand_coalition();
+        let mut covered = Coalition::empty();
+        for (index, &union) in structure.iter().enumerate() {
+            if union == Coalition::empty() {
+                return Err(InvalidCoalitionStructure::EmptyCoalition);
+            }
+            if union - grand_coalition != Coalition::empty() {
+                return Err(InvalidCoalitionStructure::OutOfGame(union));
+            }
+            if let Some(&other) = structure[..index]
+                .iter()
+                .find(|other| other.overlaps(union))
+            {
+                return Err(InvalidCoalitionStructure::Overlapping(other, union));
+            }
+            covered |= union;
+        }
+        let uncovered = grand_coalition - covered;
+        if uncovered != Coalition::empty() {
+            return Err(InvalidCoalitionStructure::Uncovered(uncovered));
+        }
+
+        let m = structure.len() as u8;
+        Ok((0..self.player_count().get())
+            .map(|player| {
+                let i = Coalition(self.player_mask(player) as usize);
+                let (k, &union) = structure
+                    .iter()
+                    .enumerate()
+                    .find(|(_, union)| union.overlaps(i))
+                    .expect("the structure covers all the players");
+                let b = union.n_members();
+                let others: Vec<_> = structure
+                    .iter()
+                    .enumerate()
+                    .filter(|&(l, _)| l != k)
+                    .map(|(_, &other)| other)
+                    .collect();
+
+                let mut value = 0.;
+                for unions in 0..1usize << others.len() {
+                    let q = others
+                        .iter()
+                        .enumerate()
+                        .filter(|(bit, _)| unions & (1 << bit) != 0)
+                        .fold(Coalition::empty(), |q, (_, &other)| q | other);
+                    let r = unions.count_ones() as u8;
+                    let unions_weight =
+                        (factorial(r) * factorial(m - r - 1)) as f64 / factorial(m) as f64;
+
+                    // all the subsets of the player's union without the player
+                    let partners = union - i;
+                    let mut t = partners;
+                    loop {
+                        let t_size = t.n_members();
+                        let partners_weight = (factorial(t_size) * factorial(b - t_size - 1))
+                            as f64
+                            / factorial(b) as f64;
+                        let s = q | t;
+                        value += unions_weight * partners_weight * (self.v(s | i) - self.v(s));
+
+                        if t == Coalition::empty() {
+                            break;
+                        }
+                        t = Coalition((t.0 - 1) & partners.0);
+                    }
+                }
+                value
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game() -> CooperativeGame<f64> {
+        CooperativeGame::new(vec![0., 1., 2., 4., 1., 3., 4., 7.]).unwrap()
+    }
+
+    #[test]
+    fn singletons_give_shapley_value() {
+        let game = game();
+        let structure: Vec<_> = game.singular_coalitions().collect();
+        let owen = game.owen_value(&structure).unwrap();
+        for (owen, shapley) in owen.iter().zip(game.x()) {
+            assert!((owen - shapley).abs() < 1e-12, "{owen} != {shapley}");
+        }
+
+        let owen = game.owen_value(&[game.grand_coalition()]).unwrap();
+        for (owen, shapley) in owen.iter().zip(game.x()) {
+            assert!((owen - shapley).abs() < 1e-12, "{owen} != {shapley}");
+        }
+    }
+
+    #[test]
+    fn unions_share_their_value() {
+        let game = game();
+        // the first player alone against the union of the other two
+        let structure = [Coalition(0b100), Coalition(0b011)];
+        let owen = game.owen_value(&structure).unwrap();
+
+        // the unions get their Shapley values in the two-player game between them:
+        // `(1 + 7 - 4) / 2 = 2` and `(4 + 7 - 1) / 2 = 5`
+        assert!((owen[0] - 2.).abs() < 1e-12);
+        assert!((owen[1] + owen[2] - 5.).abs() < 1e-12);
+        assert!((owen.iter().sum::<f64>() - game.v_i()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn invalid_structure() {
+        let game = game();
+        assert_eq!(
+            game.owen_value(&[Coalition(0b110), Coalition::empty()]),
+            Err(InvalidCoalitionStructure::EmptyCoalition)
+        );
+        assert_eq!(
+            game.owen_value(&[Coalition(0b110), Coalition(0b011)]),
+            Err(InvalidCoalitionStructure::Overlapping(
+                Coalition(0b110),
+                Coalition(0b011)
+            ))
+        );
+        assert_eq!(
+            game.owen_value(&[Coalition(0b100)]),
+            Err(InvalidCoalitionStructure::Uncovered(Coalition(0b011)))
+        );
+        assert_eq!(
+            game.owen_value(&[Coalition(0b1100), Coalition(0b011)]),
+            Err(InvalidCoalitionStructure::OutOfGame(Coalition(0b1100)))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use nalgebra::{DMatrix, DVector};
+
+use super::{Coalition, CooperativeGame};
+use crate::simplex;
+
+impl CooperativeGame<f64> {
+    /// Computes the smallest `ε` for which the `ε`-core of the game is non-empty,
+    /// i.e. there is an efficient allocation `x` such that `x(S) >= v(S) - ε`
+    /// for each coalition `S` other than the empty and the grand ones.
+    ///
+    /// The core of the game is non-empty if and only if this value is not positive.
+    /// The game of a single player has no such coalitions, so the value is `-∞` for it.
+    pub fn least_core_epsilon(&self) -> f64 {
+        let n = self.player_count().get();
+        let grand_coalition = self.grand_coalition();
+        let coalitions: Vec<_> = self
+            .coalitions()
+            .filter(|&s| s != Coalition::empty() && s != grand_coalition)
+            .collect();
+
+        // the free variables `x_i` and `ε` are represented as differences of non-negative ones:
+        // `x_i = x[2i] - x[2i + 1]` and `ε = x[2n] - x[2n + 1]`
+        let variables = 2 * (n as usize + 1);
+        let epsilon = 2 * n as usize;
+        let mut a = DMatrix::zeros(coalitions.len() + 2, variables);
+        let mut b = DVector::zeros(coalitions.len() + 2);
+        // `-x(S) - ε <= -v(S)`
+        for (row, &s) in coalitions.iter().enumerate() {
+            for player in 0..n {
+                if s.overlaps(Coalition(self.player_mask(player) as usize)) {
+                    a[(row, 2 * player as usize)] = -1.;
+                    a[(row, 2 * player as usize + 1)] = 1.;
+                }
+            }
+            a[(row, epsilon)] = -1.;
+            a[(row, epsilon + 1)] = 1.;
+            b[row] = -self.v(s);
+        }
+        // `x(I) = v(I)`
+        let efficiency = coalitions.len();
+        for player in 0..2 * n as usize {
+            let sign = if player % 2 == 0 { 1. } else { -1. };
+            a[(efficiency, player)] = sign;
+            a[(efficiency + 1, player)] = -sign;
+        }
+        b[efficiency] = *self.v_i();
+        b[efficiency + 1] = -self.v_i();
+
+        // minimize `ε` by maximizing `-ε`
+        let mut c = DVector::zeros(variables);
+        c[epsilon] = -1.;
+        c[epsilon + 1] = 1.;
+        match simplex::maximize(&c, &a, &b) {
+            Ok(solution) => -solution.value,
+            Err(simplex::Error::Unbounded) => f64::NEG_INFINITY,
+            Err(simplex::Error::Infeasible) => {
+                unreachable!("any efficient allocation is feasible for a large enough `ε`")
+            }
+        }
+    }
+
+    /// Lists the coalitions which can improve on the `imputation` by acting on their own,
+    /// i.e. the coalitions `S` for which `x(S) < v(S)`.
+    ///
+    /// The imputation is in the core of the game if and only if there are no such coalitions.
+    ///
+    /// # Panics
+    ///
+    /// If the `imputation` does not assign a share to each player.
+    pub fn blocking_coalitions(&self, imputation: &[f64]) -> Vec<Coalition> {
+        let n = self.player_count().get();
+        assert_eq!(
+            imputation.len(),
+            n as usize,
+            "imputation={imputation:?} should contain a share for each of {n} players"
+        );
+
+        self.coalitions()
+            .filter(|&s| {
+                let share: f64 = (0..n)
+                    .filter(|&player| s.overlaps(Coalition(self.player_mask(player) as usize)))
+                    .map(|player| imputation[player as usize])
+                    .sum();
+                share < *self.v(s)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_core_of_majority_game() {
+        // any two players out of three win, so the core is empty
+        let game = CooperativeGame::new(vec![0., 0., 0., 1., 0., 1., 1., 1.]).unwrap();
+        let epsilon = game.least_core_epsilon();
+        assert!((epsilon - 1. / 3.).abs() < 1e-9, "{epsilon}");
+    }
+
+    #[test]
+    fn least_core_of_game_with_core() {
+        let game = CooperativeGame::new(vec![0., 0., 0., 0., 0., 0., 0., 1.]).unwrap();
+        let epsilon = game.least_core_epsilon();
+        assert!((epsilon + 1. / 3.).abs() < 1e-9, "{epsilon}");
+
+        let single = CooperativeGame::new(vec![0., 1.]).unwrap();
+        assert_eq!(single.least_core_epsilon(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn blocking_coalitions() {
+        let game = CooperativeGame::new(vec![0., 0., 0., 1., 0., 1., 1., 1.]).unwrap();
+        // the players `1` and `2` get nothing, while together they could get `1`
+        assert_eq!(
+            game.blocking_coalitions(&[1., 0., 0.]),
+            vec![Coalition(0b011)]
+        );
+        assert_eq!(
+            game.blocking_coalitions(&[0.5, 0.5, 0.]),
+            vec![Coalition(0b011), Coalition(0b101)]
+        );
+
+        let game = CooperativeGame::new(vec![0., 0., 0., 0., 0., 0., 0., 1.]).unwrap();
+        assert!(game.blocking_coalitions(&[0.5, 0.5, 0.]).is_empty());
+    }
+}
@@ -8,7 +8,7 @@ pub trait ComplexFieldExt: ComplexField {
     ///
     /// This is defined as:
     ///
-    /// ```no_run
+    /// ```text
     /// two() == one() + one()
     /// ```
     fn two() -> Self {
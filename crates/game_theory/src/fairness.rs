@@ -0,0 +1,49 @@
+//! Measures of the fairness of payoff distributions.
+
+/// Computes the [Gini coefficient][1] of the `values`,
+/// i.e. the mean absolute difference between all pairs of values relative to twice their mean.
+///
+/// The coefficient is zero for a perfectly equal (or empty) distribution
+/// and approaches one as a single value takes everything.
+/// Negative values are allowed as long as the mean is positive,
+/// although then the coefficient may exceed one;
+/// if the values are not all equal and their mean is not positive, `NaN` is returned.
+///
+/// [1]: https://en.wikipedia.org/wiki/Gini_coefficient
+pub fn gini(values: &[f64]) -> f64 {
+    let Some(first) = values.first() else {
+        return 0.;
+    };
+    if values.iter().all(|value| value == first) {
+        return 0.;
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    if mean <= 0. {
+        return f64::NAN;
+    }
+
+    let differences: f64 = values
+        .iter()
+        .flat_map(|left| values.iter().map(move |right| (left - right).abs()))
+        .sum();
+    differences / (2. * n * n * mean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gini_coefficient() {
+        assert!((gini(&[1., 2., 3., 4.]) - 0.25).abs() < 1e-9);
+        assert!((gini(&[0., 0., 0., 1.]) - 0.75).abs() < 1e-9);
+        assert!((gini(&[-1., 3.]) - 1.).abs() < 1e-9);
+
+        assert_eq!(gini(&[2., 2., 2.]), 0.);
+        assert_eq!(gini(&[-2., -2.]), 0.);
+        assert_eq!(gini(&[]), 0.);
+        assert!(gini(&[-1., 1.]).is_nan());
+    }
+}
@@ -1,6 +1,7 @@
-use nalgebra::{DMatrix, DVector, Dyn, VecStorage, U1};
+use nalgebra::{DMatrix, DVector, Dyn, Scalar, VecStorage, U1};
 use rand::{
     distributions::uniform::{SampleRange, SampleUniform},
+    seq::index,
     Rng,
 };
 
@@ -33,3 +34,122 @@ pub fn random_vector<T: SampleUniform, R>(
             .collect(),
     ))
 }
+
+/// Generates a random `n×n` game matrix with a saddle point planted at a random cell.
+///
+/// The other values of the saddle point's row are raised and the ones of its column are lowered
+/// so that it becomes the minimum of its row and the maximum of its column.
+/// Returns the matrix and the coordinates of the planted saddle point.
+///
+/// # Panics
+///
+/// If `n` is zero.
+pub fn random_saddle_game<T: SampleUniform + Scalar + PartialOrd>(
+    mut random: impl Rng,
+    n: usize,
+    range: impl SampleRange<T> + Clone,
+) -> (DMatrix<T>, (usize, usize)) {
+    assert_ne!(n, 0, "the game should not be empty");
+
+    let mut matrix = random_matrix(&mut random, n, n, range.clone());
+    let saddle = (random.gen_range(0..n), random.gen_range(0..n));
+    let value = random.gen_range(range);
+    for (column, cell) in matrix.row_mut(saddle.0).iter_mut().enumerate() {
+        if column != saddle.1 && *cell < value {
+            *cell = value.clone();
+        }
+    }
+    for (row, cell) in matrix.column_mut(saddle.1).iter_mut().enumerate() {
+        if row != saddle.0 && *cell > value {
+            *cell = value.clone();
+        }
+    }
+    matrix[saddle] = value;
+
+    (matrix, saddle)
+}
+
+/// Generates a random `n×n` game matrix and inserts `dominated_count` rows into it,
+/// each being strictly dominated by one of the original rows.
+///
+/// The dominated rows are placed at random positions and are made
+/// by decreasing each value of a random original row by up to `1`.
+/// Returns the matrix and the sorted indices of the inserted rows.
+pub fn random_game_with_dominated(
+    mut random: impl Rng,
+    n: usize,
+    dominated_count: usize,
+    range: impl SampleRange<f64> + Clone,
+) -> (DMatrix<f64>, Vec<usize>) {
+    let original = random_matrix(&mut random, n, n, range);
+    let rows = n + dominated_count;
+    let mut dominated = index::sample(&mut random, rows, dominated_count).into_vec();
+    dominated.sort_unstable();
+
+    let mut matrix = DMatrix::zeros(rows, n);
+    let mut original_rows = original.row_iter();
+    for row in 0..rows {
+        if dominated.binary_search(&row).is_ok() {
+            let dominating = original.row(random.gen_range(0..n));
+            // the decrement is in `(0, 1]` to keep the domination strict
+            matrix.set_row(
+                row,
+                &dominating.map(|value| value - (1. - random.gen::<f64>())),
+            );
+        } else {
+            matrix.set_row(
+                row,
+                &original_rows.next().expect("there are `n` original rows"),
+            );
+        }
+    }
+
+    (matrix, dominated)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::zero_sum::Game;
+
+    #[test]
+    fn planted_saddle_point_is_found() {
+        let mut random = ChaCha20Rng::seed_from_u64(42);
+        for n in 1..8 {
+            let (matrix, saddle) = random_saddle_game(&mut random, n, -10f64..10.);
+            assert!(Game::new(matrix).saddle_points().contains(&saddle));
+        }
+    }
+
+    #[test]
+    fn planted_dominated_rows_are_eliminated() {
+        let mut random = ChaCha20Rng::seed_from_u64(42);
+        for (n, dominated_count) in [(1, 1), (3, 2), (4, 4), (6, 3)] {
+            let (matrix, dominated) =
+                random_game_with_dominated(&mut random, n, dominated_count, -10f64..10.);
+            assert_eq!(matrix.shape(), (n + dominated_count, n));
+            assert_eq!(dominated.len(), dominated_count);
+
+            let original_rows: Vec<_> = (0..matrix.nrows())
+                .filter(|row| !dominated.contains(row))
+                .collect();
+            let original = Game::new(matrix.select_rows(&original_rows)).reduced_form();
+            let reduced = Game::new(matrix).reduced_form();
+            let reduced_rows: Vec<_> = reduced
+                .rows
+                .iter()
+                .map(|row| {
+                    original_rows
+                        .iter()
+                        .position(|original| original == row)
+                        .expect("the planted rows should be eliminated")
+                })
+                .collect();
+            assert_eq!(reduced_rows, original.rows);
+            assert_eq!(reduced.columns, original.columns);
+        }
+    }
+}
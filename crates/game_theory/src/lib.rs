@@ -1,9 +1,15 @@
 //! Common utilities for game theory labs.
 pub mod zero_sum;
 
+pub mod bargaining;
 pub mod cooperative;
 pub mod ext;
+pub mod fairness;
 pub mod generate;
 pub mod highlight;
 pub mod non_cooperative;
 pub mod positional;
+pub mod simplex;
+pub mod social;
+#[cfg(feature = "prettytable")]
+pub mod table;
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use nalgebra::{DMatrix, DVector};
+use num_traits::ToPrimitive;
+
+use crate::{
+    non_cooperative::{BiMatrixGame, Pair},
+    simplex,
+};
+
+/// The outcome of [best-response dynamics](BiMatrixGame::best_response_dynamics).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DynamicsResult {
+    /// The dynamics reached a fixed point which is a pure Nash equilibrium.
+    Converged((usize, usize)),
+    /// The dynamics entered the cycle of the given strategy profiles.
+    Cycle(Vec<(usize, usize)>),
+}
+
+impl<T: PartialOrd> BiMatrixGame<T> {
+    /// Finds the strategies of player A which are best responses
+    /// to the given `column` strategy of player B.
+    pub fn best_responses_a(&self, column: usize) -> Vec<usize> {
+        let rows: Vec<_> = (0..self.0.nrows()).collect();
+        self.best_responses_a_among(&rows, column)
+    }
+
+    /// Finds the strategies of player B which are best responses
+    /// to the given `row` strategy of player A.
+    pub fn best_responses_b(&self, row: usize) -> Vec<usize> {
+        let columns: Vec<_> = (0..self.0.ncols()).collect();
+        self.best_responses_b_among(&columns, row)
+    }
+
+    /// Runs the best-response dynamics from the `start` strategy profile.
+    ///
+    /// On each step player A switches to a best response to the strategy of player B,
+    /// then B switches to a best response to the new strategy of A.
+    /// The players stick to their current strategies while those remain best responses,
+    /// otherwise they pick the first of the best responses.
+    ///
+    /// Since the dynamics is deterministic and there are finitely many profiles,
+    /// it either reaches a fixed point or repeats a profile
+    /// after at most `rows * columns` steps.
+    ///
+    /// # Panics
+    ///
+    /// If the `start` profile exceeds the dimensions of the game.
+    pub fn best_response_dynamics(&self, start: (usize, usize)) -> DynamicsResult {
+        let (row_count, column_count) = self.0.shape();
+        let (row, column) = start;
+        assert!(
+            row < row_count && column < column_count,
+            "start={start:?} exceeds the game of {row_count}×{column_count}"
+        );
+
+        let mut visited = HashMap::from([(start, 0)]);
+        let mut trajectory = vec![start];
+        let (mut row, mut column) = (row, column);
+        loop {
+            row = respond(row, self.best_responses_a(column));
+            column = respond(column, self.best_responses_b(row));
+
+            let profile = (row, column);
+            if trajectory.last() == Some(&profile) {
+                return DynamicsResult::Converged(profile);
+            }
+            if let Some(&index) = visited.get(&profile) {
+                return DynamicsResult::Cycle(trajectory.split_off(index));
+            }
+            visited.insert(profile, trajectory.len());
+            trajectory.push(profile);
+        }
+    }
+
+    fn best_responses_a_among(&self, rows: &[usize], column: usize) -> Vec<usize> {
+        let Self(game) = self;
+        let wins = |row: usize| &game[(row, column)].0;
+        best_among(rows, wins)
+    }
+
+    fn best_responses_b_among(&self, columns: &[usize], row: usize) -> Vec<usize> {
+        let Self(game) = self;
+        let wins = |column: usize| {
+            let Pair(_, win_b) = &game[(row, column)];
+            win_b
+        };
+        best_among(columns, wins)
+    }
+}
+
+impl<T: Copy + ToPrimitive> BiMatrixGame<T> {
+    /// Finds the rationalizable strategies of both players.
+    ///
+    /// These are the strategies surviving the iterated elimination of the strategies
+    /// which are never a best response to any belief, i.e. mixed strategy,
+    /// about the remaining opponent's strategies.
+    /// In two-player games these are exactly the strategies which are not
+    /// strictly dominated by a mixed strategy, so each check is a [linear program](simplex).
+    ///
+    /// # Panics
+    ///
+    /// If some payoff cannot be converted to `f64`.
+    pub fn rationalizable_strategies(&self) -> (Vec<usize>, Vec<usize>) {
+        let Self(game) = self;
+        let (row_count, column_count) = game.shape();
+        let to_f64 = |payoff: T| {
+            payoff
+                .to_f64()
+                .expect("the payoffs should be convertible to f64")
+        };
+        let a = DMatrix::from_fn(row_count, column_count, |row, column| {
+            to_f64(game[(row, column)].0)
+        });
+        // the strategies of player B are the rows
+        let b = DMatrix::from_fn(column_count, row_count, |column, row| {
+            to_f64(game[(row, column)].1)
+        });
+        let mut rows: Vec<_> = (0..row_count).collect();
+        let mut columns: Vec<_> = (0..column_count).collect();
+
+        loop {
+            let new_rows: Vec<_> = rows
+                .iter()
+                .copied()
+                .filter(|&row| !is_strictly_dominated(&a, row, &rows, &columns))
+                .collect();
+            let new_columns: Vec<_> = columns
+                .iter()
+                .copied()
+                .filter(|&column| !is_strictly_dominated(&b, column, &columns, &rows))
+                .collect();
+
+            if new_rows == rows && new_columns == columns {
+                return (rows, columns);
+            }
+            (rows, columns) = (new_rows, new_columns);
+        }
+    }
+}
+
+/// Tolerance of the total weight of the dominating strategies.
+const EPSILON: f64 = 1e-9;
+
+/// Checks if the `strategy` whose payoffs against the `opponents` are in the rows of `payoffs`
+/// is strictly dominated by a mixture of the other `strategies`.
+///
+/// The payoffs are shifted to be positive, then the total weight `Σq` of the other strategies
+/// is minimized subject to their weighted payoffs being no less than the ones of the `strategy`.
+/// The strategy is dominated if and only if the weight is less than one,
+/// since the weights normalized by it form a strictly better mixed strategy.
+fn is_strictly_dominated(
+    payoffs: &DMatrix<f64>,
+    strategy: usize,
+    strategies: &[usize],
+    opponents: &[usize],
+) -> bool {
+    let others: Vec<_> = strategies
+        .iter()
+        .copied()
+        .filter(|&other| other != strategy)
+        .collect();
+    if others.is_empty() || opponents.is_empty() {
+        return false;
+    }
+
+    let restricted = payoffs.select_rows(&others).select_columns(opponents);
+    let own = DVector::from_iterator(
+        opponents.len(),
+        opponents
+            .iter()
+            .map(|&opponent| payoffs[(strategy, opponent)]),
+    );
+    let shift = 1. - restricted.min().min(own.min());
+    let simplex::Solution { value, .. } = simplex::maximize(
+        &DVector::repeat(others.len(), -1.),
+        &-restricted.add_scalar(shift).transpose(),
+        &-own.add_scalar(shift),
+    )
+    .expect("the weights should be feasible and bounded for the shifted payoffs");
+
+    -value < 1. - EPSILON
+}
+
+fn respond(current: usize, best_responses: Vec<usize>) -> usize {
+    if best_responses.contains(&current) {
+        current
+    } else {
+        best_responses[0]
+    }
+}
+
+fn best_among<'a, T: PartialOrd + 'a>(
+    strategies: &[usize],
+    win: impl Fn(usize) -> &'a T,
+) -> Vec<usize> {
+    let Some(max_win) = strategies
+        .iter()
+        .map(|&strategy| win(strategy))
+        .reduce(|max, win| if win > max { win } else { max })
+    else {
+        return vec![];
+    };
+
+    strategies
+        .iter()
+        .copied()
+        .filter(|&strategy| win(strategy) >= max_win)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn best_responses() {
+        let the_family_conflict = BiMatrixGame::new(dmatrix![
+            Pair(4., 1.), Pair(0., 0.);
+            Pair(0., 0.), Pair(1., 4.);
+        ]);
+        assert_eq!(the_family_conflict.best_responses_a(0), vec![0]);
+        assert_eq!(the_family_conflict.best_responses_a(1), vec![1]);
+        assert_eq!(the_family_conflict.best_responses_b(1), vec![1]);
+    }
+
+    #[test]
+    fn rationalizability_eliminates_mixed_dominated_strategies() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(3, 1), Pair(0, 0);
+            Pair(0, 0), Pair(3, 1);
+            Pair(1, 0), Pair(1, 0);
+        ]);
+
+        // the last row is not dominated by any other pure row...
+        let matrix = &game.0;
+        assert!(
+            (0..2).all(|row| (0..2).any(|column| matrix[(row, column)].0 < matrix[(2, column)].0))
+        );
+        // ...but is dominated by mixing them, so it is never a best response
+        assert_eq!(game.rationalizable_strategies(), (vec![0, 1], vec![0, 1]));
+    }
+
+    #[test]
+    fn rationalizable_strategies_iterate() {
+        // after the last column is eliminated, the last row is never a best response
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(2i64, 2), Pair(0, 1), Pair(0, 0);
+            Pair(1, 0), Pair(2, 2), Pair(0, 0);
+            Pair(0, 0), Pair(0, 0), Pair(3, -1);
+        ]);
+        assert_eq!(game.rationalizable_strategies(), (vec![0, 1], vec![0, 1]));
+    }
+
+    #[test]
+    fn rationalizability_allows_mixed_beliefs() {
+        // the last row is only a best response to the belief mixing both columns
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(3, 0), Pair(0, 1);
+            Pair(0, 1), Pair(3, 0);
+            Pair(2, 1), Pair(2, 1);
+        ]);
+        assert_eq!(
+            game.rationalizable_strategies(),
+            (vec![0, 1, 2], vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn best_response_dynamics() {
+        let matching_pennies = BiMatrixGame::new(dmatrix![
+            Pair(1, -1), Pair(-1, 1);
+            Pair(-1, 1), Pair(1, -1);
+        ]);
+        assert_eq!(
+            matching_pennies.best_response_dynamics((0, 0)),
+            DynamicsResult::Cycle(vec![(0, 1), (1, 0)])
+        );
+
+        let prisoners_dilemma = BiMatrixGame::new(dmatrix![
+            Pair(-5, -5), Pair(0, -10);
+            Pair(-10, 0), Pair(-1, -1);
+        ]);
+        assert_eq!(
+            prisoners_dilemma.best_response_dynamics((1, 1)),
+            DynamicsResult::Converged((0, 0))
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use nalgebra::RealField;
+
+use crate::non_cooperative::{BiMatrixGame, Pair};
+
+/// Best response of a player in a `2×2` game
+/// as a function of the probability `p` with which the opponent chooses the first strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BestResponseCurve<T> {
+    /// The probability `p` at which the player is indifferent between the strategies.
+    ///
+    /// [`None`] if the best response does not depend on `p`.
+    pub threshold: Option<T>,
+    /// The best response for the probabilities below the threshold.
+    pub below: usize,
+    /// The best response for the probabilities above the threshold.
+    pub above: usize,
+}
+
+impl<T: RealField + Copy> BestResponseCurve<T> {
+    /// Creates the curve by the advantage of the first strategy over the second one
+    /// when the opponent chooses its first strategy with the probabilities `0` and `1`.
+    fn from_advantages(at_zero: T, at_one: T) -> Self {
+        // the advantage is linear in `p`
+        let slope = at_one - at_zero;
+        if !slope.is_zero() {
+            let threshold = -at_zero / slope;
+            if threshold >= T::zero() && threshold <= T::one() {
+                let above = usize::from(slope < T::zero());
+                return Self {
+                    threshold: Some(threshold),
+                    below: 1 - above,
+                    above,
+                };
+            }
+        }
+
+        let best = usize::from(at_zero + at_one < T::zero());
+        Self {
+            threshold: None,
+            below: best,
+            above: best,
+        }
+    }
+}
+
+/// The [best-response curves](BiMatrixGame::best_response_curves_2x2) of both players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BestResponseCurves<T> {
+    /// The best response of player A to the probability of B choosing the first column.
+    pub a: BestResponseCurve<T>,
+    /// The best response of player B to the probability of A choosing the first row.
+    pub b: BestResponseCurve<T>,
+}
+
+impl<T: Copy> BestResponseCurves<T> {
+    /// Gets the intersection of the curves at which both players are indifferent,
+    /// i.e. the mixed Nash equilibrium given by the probabilities
+    /// of A choosing the first row and B choosing the first column.
+    pub fn mixed_equilibrium(&self) -> Option<(T, T)> {
+        Some((self.b.threshold?, self.a.threshold?))
+    }
+}
+
+impl<T: RealField + Copy> BiMatrixGame<T> {
+    /// Computes the best responses of the players in a `2×2` game
+    /// as functions of the opponent's mixed strategy,
+    /// which are used to draw the best-response diagram of the game.
+    ///
+    /// Returns [`None`] if the game is not `2×2`.
+    pub fn best_response_curves_2x2(&self) -> Option<BestResponseCurves<T>> {
+        let Self(game) = self;
+        if game.shape() != (2, 2) {
+            return None;
+        }
+
+        let a = |row, column| {
+            let Pair(a, _) = game[(row, column)];
+            a
+        };
+        let b = |row, column| {
+            let Pair(_, b) = game[(row, column)];
+            b
+        };
+        Some(BestResponseCurves {
+            a: BestResponseCurve::from_advantages(a(0, 1) - a(1, 1), a(0, 0) - a(1, 0)),
+            b: BestResponseCurve::from_advantages(b(1, 0) - b(1, 1), b(0, 0) - b(0, 1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn the_family_conflict() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(4f64, 1.), Pair(0., 0.);
+            Pair(0., 0.), Pair(1., 4.);
+        ]);
+        let curves = game.best_response_curves_2x2().unwrap();
+
+        assert_eq!(curves.a.threshold, Some(0.2));
+        assert_eq!((curves.a.below, curves.a.above), (1, 0));
+        assert_eq!(curves.b.threshold, Some(0.8));
+        assert_eq!((curves.b.below, curves.b.above), (1, 0));
+        assert_eq!(curves.mixed_equilibrium(), Some((0.8, 0.2)));
+    }
+
+    #[test]
+    fn dominant_strategies() {
+        let prisoners_dilemma = BiMatrixGame::new(dmatrix![
+            Pair(-5f64, -5.), Pair(0., -10.);
+            Pair(-10., 0.), Pair(-1., -1.);
+        ]);
+        let curves = prisoners_dilemma.best_response_curves_2x2().unwrap();
+
+        let confess = BestResponseCurve {
+            threshold: None,
+            below: 0,
+            above: 0,
+        };
+        assert_eq!(
+            curves,
+            BestResponseCurves {
+                a: confess,
+                b: confess
+            }
+        );
+        assert_eq!(curves.mixed_equilibrium(), None);
+
+        let game = BiMatrixGame::new(dmatrix![Pair(1f64, 1.), Pair(1., 1.)]);
+        assert_eq!(game.best_response_curves_2x2(), None);
+    }
+}
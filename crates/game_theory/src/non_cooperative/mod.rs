@@ -1,14 +1,32 @@
-use std::{fmt, fmt::Formatter};
+use std::{fmt, fmt::Formatter, ops::Neg};
 
-use nalgebra::{ComplexField, DMatrix, Dyn, VecStorage};
+pub use best_response::DynamicsResult;
+pub use curves::{BestResponseCurve, BestResponseCurves};
+pub use n_matrix::NMatrixGame;
+pub(crate) use n_matrix::Profiles;
+use nalgebra::{ComplexField, DMatrix, Dyn, Scalar, VecStorage};
+pub use nfg::NfgParseError;
 pub use pair::Pair;
 use rand::{
     distributions::uniform::{SampleRange, SampleUniform},
     Rng,
 };
+pub use refinement::EquilibriumLabel;
+pub use solve::GameSolutionReport;
 
+use crate::{generate::random_matrix, zero_sum::DGame};
+
+mod best_response;
+mod curves;
+mod n_matrix;
+mod nfg;
 mod optimal;
 mod pair;
+mod potential;
+mod refinement;
+mod regret;
+mod solve;
+mod symmetric;
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -29,9 +47,43 @@ impl<M: fmt::Display> fmt::Display for Game<M> {
 
 pub type BiMatrixGame<T> = Game<DMatrix<Pair<T>>>;
 
+/// The values of the game for both players and their mixed strategies.
+pub type MixedBalancedStrategies<T> = ((T, T), (DMatrix<T>, DMatrix<T>));
+
 pub use optimal::OptimalBiMatrixStrategy;
 
+/// Error returned when the payoff matrices of the players have different shapes.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("payoff matrices have different shapes: {a:?} and {b:?}")]
+pub struct ShapeMismatch {
+    pub a: (usize, usize),
+    pub b: (usize, usize),
+}
+
 impl<T> BiMatrixGame<T> {
+    /// Creates the game from the separate payoff matrices of players A and B.
+    pub fn from_matrices(a: DMatrix<T>, b: DMatrix<T>) -> Result<Self, ShapeMismatch>
+    where
+        T: Clone,
+    {
+        if a.shape() != b.shape() {
+            return Err(ShapeMismatch {
+                a: a.shape(),
+                b: b.shape(),
+            });
+        }
+
+        let (rows, columns) = a.shape();
+        Ok(Self(DMatrix::from_vec_storage(VecStorage::new(
+            Dyn(rows),
+            Dyn(columns),
+            a.iter()
+                .zip(b.iter())
+                .map(|(a, b)| Pair(a.clone(), b.clone()))
+                .collect(),
+        ))))
+    }
+
     pub fn random<G>(
         mut random: impl Rng,
         rows: usize,
@@ -56,7 +108,61 @@ impl<T> BiMatrixGame<T> {
         )))
     }
 
-    pub fn mixed_balanced_strategies(&self) -> Option<((T, T), (DMatrix<T>, DMatrix<T>))>
+    /// Generates a random zero-sum game, i.e. the one in which B loses what A wins.
+    pub fn random_zero_sum(
+        random: impl Rng,
+        rows: usize,
+        columns: usize,
+        range: impl SampleRange<T> + Clone,
+    ) -> Self
+    where
+        T: SampleUniform + Scalar + Neg<Output = T>,
+    {
+        Self(random_matrix(random, rows, columns, range).map(|win: T| Pair(win.clone(), -win)))
+    }
+
+    /// Converts the game into the zero-sum game of player A
+    /// if the wins of player B are always the losses of player A.
+    pub fn to_zero_sum(&self) -> Option<DGame<T>>
+    where
+        T: Scalar + Neg<Output = T>,
+    {
+        let Self(game) = self;
+        game.iter()
+            .all(|Pair(a, b)| -a.clone() == *b)
+            .then(|| DGame::new(game.map(|Pair(a, _)| a)))
+    }
+
+    /// Creates the game restricted to the given strategies of the players.
+    ///
+    /// # Panics
+    ///
+    /// If any of the indices exceeds the dimensions of the game.
+    pub fn subgame(&self, rows: &[usize], columns: &[usize]) -> Self
+    where
+        T: Clone,
+    {
+        let Self(game) = self;
+        let (row_count, column_count) = game.shape();
+        if let Some(row) = rows.iter().find(|&&row| row >= row_count) {
+            panic!("row={row} exceeds row_count={row_count}");
+        }
+        if let Some(column) = columns.iter().find(|&&column| column >= column_count) {
+            panic!("column={column} exceeds column_count={column_count}");
+        }
+
+        // `VecStorage` uses column-major order
+        Self(DMatrix::from_vec_storage(VecStorage::new(
+            Dyn(rows.len()),
+            Dyn(columns.len()),
+            columns
+                .iter()
+                .flat_map(|&column| rows.iter().map(move |&row| game[(row, column)].clone()))
+                .collect(),
+        )))
+    }
+
+    pub fn mixed_balanced_strategies(&self) -> Option<MixedBalancedStrategies<T>>
     where
         T: ComplexField + Copy,
     {
@@ -91,6 +197,28 @@ impl<T> BiMatrixGame<T> {
         Some(((v1, v2), (x, y.transpose())))
     }
 
+    /// Checks if the players of the game are interchangeable,
+    /// i.e. the game is square and the payoff of A in `(i, j)` equals the payoff of B in `(j, i)`.
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: Scalar,
+    {
+        if !self.0.is_square() {
+            return false;
+        }
+
+        let (a, b) = self.split();
+        a == b.transpose()
+    }
+
+    /// Gets the payoff matrices of players A and B.
+    pub fn payoff_matrices(&self) -> (DMatrix<T>, DMatrix<T>)
+    where
+        T: Clone,
+    {
+        self.split()
+    }
+
     fn split(&self) -> (DMatrix<T>, DMatrix<T>)
     where
         T: Clone,
@@ -113,3 +241,114 @@ impl<T> BiMatrixGame<T> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+
+    #[test]
+    fn subgame() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1, 2), Pair(3, 4), Pair(5, 6);
+            Pair(7, 8), Pair(9, 10), Pair(11, 12);
+            Pair(13, 14), Pair(15, 16), Pair(17, 18);
+        ]);
+        assert_eq!(
+            game.subgame(&[0, 2], &[2, 1]),
+            BiMatrixGame::new(dmatrix![
+                Pair(5, 6), Pair(3, 4);
+                Pair(17, 18), Pair(15, 16);
+            ])
+        );
+    }
+
+    #[test]
+    fn from_matrices() {
+        let a = dmatrix![1, 2, 3; 4, 5, 6];
+        let b = dmatrix![6, 5, 4; 3, 2, 1];
+        let game = BiMatrixGame::from_matrices(a.clone(), b.clone()).unwrap();
+        assert_eq!(
+            game,
+            BiMatrixGame::new(dmatrix![
+                Pair(1, 6), Pair(2, 5), Pair(3, 4);
+                Pair(4, 3), Pair(5, 2), Pair(6, 1);
+            ])
+        );
+        assert_eq!(game.payoff_matrices(), (a, b));
+
+        assert_eq!(
+            BiMatrixGame::from_matrices(dmatrix![1, 2], dmatrix![1; 2]),
+            Err(ShapeMismatch {
+                a: (1, 2),
+                b: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let prisoners_dilemma = BiMatrixGame::new(dmatrix![
+            Pair(-5, -5), Pair(0, -10);
+            Pair(-10, 0), Pair(-1, -1);
+        ]);
+        assert!(prisoners_dilemma.is_symmetric());
+
+        let the_family_conflict = BiMatrixGame::new(dmatrix![
+            Pair(4, 1), Pair(0, 0);
+            Pair(0, 0), Pair(1, 4);
+        ]);
+        assert!(!the_family_conflict.is_symmetric());
+
+        assert!(!BiMatrixGame::new(dmatrix![Pair(1, 1), Pair(1, 1)]).is_symmetric());
+    }
+
+    #[test]
+    fn to_zero_sum() {
+        let game = BiMatrixGame::new(dmatrix![Pair(1, -1), Pair(-2, 2)]);
+        assert_eq!(game.to_zero_sum(), Some(DGame::new(dmatrix![1, -2])));
+        assert_eq!(BiMatrixGame::new(dmatrix![Pair(1, 1)]).to_zero_sum(), None);
+    }
+
+    /// The mixed Nash equilibrium found by the equalizing system of the bimatrix game
+    /// should agree with the independent [linear programming](DGame::solve_linear_programming)
+    /// solution of the zero-sum game whenever the former is a proper equilibrium,
+    /// i.e. all the strategies are used, while the value of B is always the negated value of A.
+    ///
+    /// The games are generated reproducibly from the seed `42`.
+    #[test]
+    fn zero_sum_solvers_agree() {
+        let mut random = ChaCha20Rng::seed_from_u64(42);
+        let mut compared = 0;
+        for _ in 0..50 {
+            let game = BiMatrixGame::random_zero_sum(&mut random, 3, 3, -10.0..10.);
+            let zero_sum = game.to_zero_sum().unwrap();
+
+            let ((value_a, value_b), (x, y)) = game.mixed_balanced_strategies().unwrap();
+            assert!((value_a + value_b).abs() < 1e-9);
+            if x.iter().chain(&y).any(|&probability| probability < 0.) {
+                continue;
+            }
+
+            let (value, lp_x, lp_y) = zero_sum.solve_linear_programming().unwrap();
+            assert!((value_a - value).abs() < 1e-9, "{value_a} != {value}");
+            assert!((x.transpose() - lp_x).norm() < 1e-9);
+            assert!((y.transpose() - lp_y).norm() < 1e-9);
+            compared += 1;
+        }
+        assert!(
+            compared > 0,
+            "no completely mixed equilibria were generated"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn subgame_out_of_bounds() {
+        let game = BiMatrixGame::new(dmatrix![Pair(1, 2), Pair(3, 4)]);
+        let _ = game.subgame(&[0], &[2]);
+    }
+}
@@ -0,0 +1,156 @@
+/// A non-cooperative game of `N` players given by the payoffs of each of the pure strategy profiles.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NMatrixGame<T> {
+    /// The number of strategies of each of the players.
+    strategies: Vec<usize>,
+    /// The payoffs of all the players for each of the profiles in lexicographic order.
+    payoffs: Vec<Vec<T>>,
+}
+
+impl<T> NMatrixGame<T> {
+    /// Creates the game by computing the payoffs of all the players for each of the profiles.
+    ///
+    /// Returns [`None`] if there are no players, some player has no strategies,
+    /// or the number of payoffs differs from the number of players.
+    pub fn from_fn(
+        strategies: Vec<usize>,
+        mut payoffs: impl FnMut(&[usize]) -> Vec<T>,
+    ) -> Option<Self> {
+        if strategies.is_empty() || strategies.contains(&0) {
+            return None;
+        }
+
+        let players = strategies.len();
+        let payoffs = Profiles::new(&strategies)
+            .map(|profile| {
+                let payoffs = payoffs(&profile);
+                (payoffs.len() == players).then_some(payoffs)
+            })
+            .collect::<Option<_>>()?;
+        Some(Self {
+            strategies,
+            payoffs,
+        })
+    }
+
+    pub fn players(&self) -> usize {
+        self.strategies.len()
+    }
+
+    /// Gets the number of strategies of each of the players.
+    pub fn strategies(&self) -> &[usize] {
+        &self.strategies
+    }
+
+    /// Gets the payoff of the `player` in the given strategy `profile`.
+    ///
+    /// # Panics
+    ///
+    /// If the profile or the player exceeds the dimensions of the game.
+    pub fn payoff(&self, profile: &[usize], player: usize) -> &T {
+        &self.payoffs[self.index(profile)][player]
+    }
+
+    /// Finds all the pure Nash equilibria by checking every profile
+    /// against all the unilateral deviations.
+    ///
+    /// This takes `O(Πmᵢ · Σmᵢ)` time for the players having `mᵢ` strategies.
+    pub fn pure_nash_equilibria(&self) -> Vec<Vec<usize>>
+    where
+        T: PartialOrd,
+    {
+        Profiles::new(&self.strategies)
+            .filter(|profile| {
+                (0..self.players()).all(|player| {
+                    let payoff = self.payoff(profile, player);
+                    let mut deviation = profile.clone();
+                    (0..self.strategies[player]).all(|strategy| {
+                        deviation[player] = strategy;
+                        self.payoff(&deviation, player) <= payoff
+                    })
+                })
+            })
+            .collect()
+    }
+
+    fn index(&self, profile: &[usize]) -> usize {
+        assert_eq!(
+            profile.len(),
+            self.players(),
+            "the profile should have a strategy per player"
+        );
+        profile
+            .iter()
+            .zip(&self.strategies)
+            .fold(0, |index, (&strategy, &strategies)| {
+                assert!(
+                    strategy < strategies,
+                    "strategy={strategy} exceeds {strategies}"
+                );
+                index * strategies + strategy
+            })
+    }
+}
+
+/// Iterator over the strategy profiles in lexicographic order.
+pub(crate) struct Profiles<'a> {
+    strategies: &'a [usize],
+    next: Option<Vec<usize>>,
+}
+
+impl<'a> Profiles<'a> {
+    pub(crate) fn new(strategies: &'a [usize]) -> Self {
+        Self {
+            strategies,
+            next: Some(vec![0; strategies.len()]),
+        }
+    }
+}
+
+impl Iterator for Profiles<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+
+        let mut next = current.clone();
+        for (strategy, &strategies) in next.iter_mut().zip(self.strategies).rev() {
+            *strategy += 1;
+            if *strategy < strategies {
+                self.next = Some(next);
+                break;
+            }
+            *strategy = 0;
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pure_nash_equilibria() {
+        // each player wants to match the strategy of the next one
+        let game = NMatrixGame::from_fn(vec![2, 2, 2], |profile| {
+            (0..3)
+                .map(|player| u8::from(profile[player] == profile[(player + 1) % 3]))
+                .collect()
+        })
+        .unwrap();
+
+        assert_eq!(*game.payoff(&[0, 0, 1], 1), 0);
+        assert_eq!(
+            game.pure_nash_equilibria(),
+            vec![vec![0, 0, 0], vec![1, 1, 1]]
+        );
+    }
+
+    #[test]
+    fn invalid_games() {
+        assert_eq!(NMatrixGame::<u8>::from_fn(vec![], |_| vec![]), None);
+        assert_eq!(NMatrixGame::from_fn(vec![2, 0], |_| vec![0, 0]), None);
+        assert_eq!(NMatrixGame::from_fn(vec![2, 2], |_| vec![0]), None);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fmt::Display, str::FromStr};
+
+use nalgebra::{DMatrix, Dyn, Scalar, VecStorage};
+use peg::{error::ParseError, str::LineCol};
+
+use crate::non_cooperative::{BiMatrixGame, Pair};
+
+impl<T: Display> BiMatrixGame<T> {
+    /// Exports the game in the payoff version of the [Gambit][1] normal form game format.
+    ///
+    /// Player A is the first player whose strategies are the rows.
+    /// The payoffs are listed by the outcomes with the strategy of the first player changing first,
+    /// i.e. by the columns of the matrix.
+    ///
+    /// [1]: https://gambitproject.readthedocs.io/en/latest/formats.html
+    #[must_use]
+    pub fn to_nfg(&self) -> String {
+        let Self(game) = self;
+        let (rows, columns) = game.shape();
+
+        // the matrix is stored by the columns
+        let payoffs = game
+            .iter()
+            .map(|Pair(a, b)| format!("{a} {b}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("NFG 1 R \"\" {{ \"A\" \"B\" }} {{ {rows} {columns} }}\n\n{payoffs}\n")
+    }
+}
+
+impl<T: Scalar + FromStr> BiMatrixGame<T> {
+    /// Imports the game from the payoff version of the [Gambit][1] normal form game format
+    /// as [exported](Self::to_nfg) by Gambit.
+    ///
+    /// The strategies may be given either by their counts or by their names.
+    /// Only the games of two players are supported.
+    ///
+    /// [1]: https://gambitproject.readthedocs.io/en/latest/formats.html
+    pub fn from_nfg(s: &str) -> Result<Self, NfgParseError> {
+        Ok(nfg::game(s)?)
+    }
+}
+
+/// Error returned when the game in the [Gambit format](BiMatrixGame::from_nfg) cannot be parsed.
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub struct NfgParseError(#[from] ParseError<LineCol>);
+
+peg::parser! {
+    grammar nfg() for str {
+        pub rule game<T: Scalar + FromStr>() -> BiMatrixGame<T>
+            = _ "NFG" __ "1" __ ("R" / "D") _ string() _
+              "{" _ players:(string() ** _) _ "}" _
+              "{" _ strategies:(strategy_count() ** _) _ "}" _
+              (string() _)?
+              payoffs:(payoff() ** _) _
+        {?
+            if players.len() != 2 || strategies.len() != 2 {
+                return Err("only the games of two players are supported");
+            }
+            let (rows, columns) = (strategies[0], strategies[1]);
+            let outcomes = rows.checked_mul(columns).ok_or("there are too many strategies")?;
+            if payoffs.len() != outcomes.checked_mul(2).ok_or("there are too many strategies")? {
+                return Err("the number of payoffs does not match the strategies");
+            }
+
+            // both formats list the outcomes by the columns
+            let mut payoffs = payoffs.into_iter();
+            let data = (0..outcomes)
+                .map(|_| Pair(payoffs.next().unwrap(), payoffs.next().unwrap()))
+                .collect();
+            Ok(BiMatrixGame::new(DMatrix::from_vec_storage(VecStorage::new(
+                Dyn(rows),
+                Dyn(columns),
+                data,
+            ))))
+        }
+
+        rule _() = [' ' | '\t' | '\r' | '\n']*
+
+        rule __() = [' ' | '\t' | '\r' | '\n']+
+
+        rule string() -> &'input str = $("\"" ("\\\"" / !"\"" [_])* "\"")
+
+        rule strategy_count() -> usize
+            = count:$(['0'..='9']+) {? count.parse().or(Err("invalid strategy count")) }
+            / "{" _ names:(string() ** _) _ "}" { names.len() }
+
+        rule payoff<T: FromStr>() -> T
+            = payoff:$((!['{' | '}' | '"' | ' ' | '\t' | '\r' | '\n'] [_])+)
+        {?
+            T::from_str(payoff).or(Err("failed to parse payoff"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn prisoners_dilemma() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-5, -5), Pair(0, -10);
+            Pair(-10, 0), Pair(-1, -1);
+        ]);
+        assert_eq!(
+            game.to_nfg(),
+            "NFG 1 R \"\" { \"A\" \"B\" } { 2 2 }\n\n-5 -5 -10 0 0 -10 -1 -1\n"
+        );
+    }
+
+    #[test]
+    fn rectangular() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1, 2), Pair(3, 4), Pair(5, 6);
+            Pair(7, 8), Pair(9, 10), Pair(11, 12);
+        ]);
+        assert_eq!(
+            game.to_nfg(),
+            "NFG 1 R \"\" { \"A\" \"B\" } { 2 3 }\n\n1 2 7 8 3 4 9 10 5 6 11 12\n"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1., 2.5), Pair(-3., 4.), Pair(5., 6.);
+            Pair(7., 8.), Pair(9., -10.), Pair(1e-3, 12.);
+        ]);
+        assert_eq!(BiMatrixGame::from_nfg(&game.to_nfg()).unwrap(), game);
+    }
+
+    #[test]
+    fn gambit_fixture() {
+        let game = BiMatrixGame::<i32>::from_nfg(include_str!("../../fixtures/e02.nfg")).unwrap();
+        assert_eq!(
+            game,
+            BiMatrixGame::new(dmatrix![
+                Pair(1, 1), Pair(1, 1);
+                Pair(0, 2), Pair(0, 3);
+                Pair(0, 2), Pair(2, 0);
+            ])
+        );
+    }
+
+    #[test]
+    fn named_strategies() {
+        let game = BiMatrixGame::<i32>::from_nfg(
+            r#"NFG 1 R "Prisoner's \"dilemma\"" { "Player 1" "Player 2" }
+            { { "C" "D" } { "C" "D" } }
+            "with a comment"
+
+            -5 -5 -10 0 0 -10 -1 -1"#,
+        )
+        .unwrap();
+        assert_eq!(
+            game,
+            BiMatrixGame::new(dmatrix![
+                Pair(-5, -5), Pair(0, -10);
+                Pair(-10, 0), Pair(-1, -1);
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(BiMatrixGame::<i32>::from_nfg("NFG 1 R \"\" { \"A\" } { 2 }\n1 2").is_err());
+        assert!(
+            BiMatrixGame::<i32>::from_nfg("NFG 1 R \"\" { \"A\" \"B\" } { 1 2 }\n1 2").is_err()
+        );
+        assert!(
+            BiMatrixGame::<i32>::from_nfg("NFG 1 R \"\" { \"A\" \"B\" } { 1 1 }\n1 x").is_err()
+        );
+    }
+}
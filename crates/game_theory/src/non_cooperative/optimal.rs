@@ -49,7 +49,21 @@ impl<T> BiMatrixGame<T> {
             })
     }
 
-    fn is_nash_equilibrium(&self, (row, column): (usize, usize)) -> bool
+    /// Checks if the given strategy profile is a Nash equilibrium.
+    ///
+    /// ```
+    /// use game_theory::non_cooperative::{BiMatrixGame, Pair};
+    /// use nalgebra::dmatrix;
+    ///
+    /// let the_family_conflict = BiMatrixGame::new(dmatrix![
+    ///     Pair(4., 1.), Pair(0., 0.);
+    ///     Pair(0., 0.), Pair(1., 4.);
+    /// ]);
+    /// assert!(the_family_conflict.is_nash_equilibrium((0, 0)));
+    /// assert!(!the_family_conflict.is_nash_equilibrium((1, 0)));
+    /// assert!(the_family_conflict.is_nash_equilibrium((1, 1)));
+    /// ```
+    pub fn is_nash_equilibrium(&self, (row, column): (usize, usize)) -> bool
     where
         T: PartialOrd,
     {
@@ -64,7 +78,22 @@ impl<T> BiMatrixGame<T> {
                 .all(|other_column| game[(row, other_column)].1 < *win_b)
     }
 
-    fn is_pareto_efficient(&self, (row, column): (usize, usize)) -> bool
+    /// Checks if the given strategy profile is Pareto-efficient.
+    ///
+    /// ```
+    /// use game_theory::non_cooperative::{BiMatrixGame, Pair};
+    /// use nalgebra::dmatrix;
+    ///
+    /// let prisoners_dilemma = BiMatrixGame::new(dmatrix![
+    ///     Pair(-5., -5.), Pair(0., -10.);
+    ///     Pair(-10., 0.), Pair(-1., -1.);
+    /// ]);
+    /// assert!(!prisoners_dilemma.is_pareto_efficient((0, 0)));
+    /// assert!(prisoners_dilemma.is_pareto_efficient((1, 1)));
+    /// // the only Nash equilibrium is not Pareto-efficient
+    /// assert!(prisoners_dilemma.is_nash_equilibrium((0, 0)));
+    /// ```
+    pub fn is_pareto_efficient(&self, (row, column): (usize, usize)) -> bool
     where
         T: PartialOrd,
     {
@@ -14,9 +14,13 @@ impl<T: Debug> Debug for Pair<T> {
     }
 }
 
+/// The precision, if any, is applied to both of the components.
 impl<T: Display> Display for Pair<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let Self(l, r) = self;
-        write!(f, "({l}, {r})")
+        match f.precision() {
+            Some(precision) => write!(f, "({l:.precision$}, {r:.precision$})"),
+            None => write!(f, "({l}, {r})"),
+        }
     }
 }
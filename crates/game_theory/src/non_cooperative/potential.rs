@@ -0,0 +1,77 @@
+use std::ops::{Add, Sub};
+
+use nalgebra::{DMatrix, Scalar};
+
+use crate::non_cooperative::{BiMatrixGame, Pair};
+
+impl<T: Scalar + Add<Output = T> + Sub<Output = T>> BiMatrixGame<T> {
+    /// Constructs the [exact potential][1] of the game, if it is a potential game.
+    ///
+    /// The potential `P` is such that any unilateral deviation changes it
+    /// exactly by the change of the deviating player's payoff.
+    /// It is built by fixing `P(0, 0) = 0`, accumulating the differences of A's payoffs
+    /// along the first column and the differences of B's payoffs along the rows,
+    /// after which the consistency with A's payoffs is checked for all the other columns.
+    ///
+    /// The pure Nash equilibria of a potential game include the maxima of its potential.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Potential_game
+    pub fn exact_potential(&self) -> Option<DMatrix<T>> {
+        let Self(game) = self;
+        if game.is_empty() {
+            return None;
+        }
+
+        let a = |row: usize, column: usize| {
+            let Pair(a, _) = &game[(row, column)];
+            a.clone()
+        };
+        let b = |row: usize, column: usize| {
+            let Pair(_, b) = &game[(row, column)];
+            b.clone()
+        };
+        let potential = DMatrix::from_fn(game.nrows(), game.ncols(), |row, column| {
+            a(row, 0) - a(0, 0) + b(row, column) - b(row, 0)
+        });
+
+        let is_consistent = (1..game.nrows()).all(|row| {
+            (1..game.ncols()).all(|column| {
+                potential[(row, column)].clone() - potential[(0, column)].clone()
+                    == a(row, column) - a(0, column)
+            })
+        });
+        is_consistent.then_some(potential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn congestion_game_is_potential() {
+        // each of the players picks one of the two roads and suffers from its load
+        let congestion = BiMatrixGame::new(dmatrix![
+            Pair(-2, -2), Pair(-1, -1);
+            Pair(-1, -1), Pair(-2, -2);
+        ]);
+        assert_eq!(
+            congestion.exact_potential(),
+            Some(dmatrix![
+                0, 1;
+                1, 0;
+            ])
+        );
+    }
+
+    #[test]
+    fn matching_pennies_is_not_potential() {
+        let matching_pennies = BiMatrixGame::new(dmatrix![
+            Pair(1, -1), Pair(-1, 1);
+            Pair(-1, 1), Pair(1, -1);
+        ]);
+        assert_eq!(matching_pennies.exact_potential(), None);
+    }
+}
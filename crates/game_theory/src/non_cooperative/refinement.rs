@@ -0,0 +1,114 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::non_cooperative::{BiMatrixGame, Pair};
+
+/// The refinements which a pure Nash equilibrium [satisfies](BiMatrixGame::classify_equilibria).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EquilibriumLabel {
+    /// The equilibrium as `(row, column)`.
+    pub coordinate: (usize, usize),
+    /// Whether the joint payoff of the equilibrium is not less than the one of any other equilibrium.
+    pub payoff_dominant: bool,
+    /// Whether the equilibrium risk-dominates all the other equilibria.
+    pub risk_dominant: bool,
+}
+
+impl<T> BiMatrixGame<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Labels each of the pure Nash equilibria of the game
+    /// by the refinements used to select among multiple ones, e.g. in coordination games.
+    ///
+    /// An equilibrium is payoff-dominant if no other equilibrium has the higher joint payoff.
+    /// An equilibrium risk-dominates another one in the `2×2` game formed by their strategies
+    /// if the product of the losses of the players deviating from it is not less
+    /// than the one for the other equilibrium.
+    /// Since the equilibria sharing a strategy do not form such a game, they are not compared.
+    #[must_use]
+    pub fn classify_equilibria(&self) -> Vec<EquilibriumLabel> {
+        let Self(game) = self;
+        let equilibria: Vec<_> = self
+            .nash_equilibriums()
+            .map(|equilibrium| equilibrium.coordinate)
+            .collect();
+        let joint_payoff = |(row, column): (usize, usize)| {
+            let Pair(a, b) = game[(row, column)];
+            a + b
+        };
+        // the product of the losses of the players deviating from `(row, column)`
+        // to the strategies of `(other_row, other_column)`
+        let deviation_losses = |(row, column): (usize, usize), (other_row, other_column)| {
+            let Pair(a, b) = game[(row, column)];
+            let Pair(deviated_a, _) = game[(other_row, column)];
+            let Pair(_, deviated_b) = game[(row, other_column)];
+            (a - deviated_a) * (b - deviated_b)
+        };
+
+        equilibria
+            .iter()
+            .map(|&coordinate| {
+                let payoff_dominant = equilibria
+                    .iter()
+                    .all(|&other| joint_payoff(coordinate) >= joint_payoff(other));
+                let risk_dominant = equilibria
+                    .iter()
+                    .filter(|&&(row, column)| row != coordinate.0 && column != coordinate.1)
+                    .all(|&other| {
+                        deviation_losses(coordinate, other) >= deviation_losses(other, coordinate)
+                    });
+                EquilibriumLabel {
+                    coordinate,
+                    payoff_dominant,
+                    risk_dominant,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn stag_hunt() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(4, 4), Pair(0, 3);
+            Pair(3, 0), Pair(3, 3);
+        ]);
+        assert_eq!(
+            game.classify_equilibria(),
+            vec![
+                EquilibriumLabel {
+                    coordinate: (0, 0),
+                    payoff_dominant: true,
+                    risk_dominant: false,
+                },
+                EquilibriumLabel {
+                    coordinate: (1, 1),
+                    payoff_dominant: false,
+                    risk_dominant: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn single_equilibrium_is_both() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-5, -5), Pair(0, -10);
+            Pair(-10, 0), Pair(-1, -1);
+        ]);
+        assert_eq!(
+            game.classify_equilibria(),
+            vec![EquilibriumLabel {
+                coordinate: (0, 0),
+                payoff_dominant: true,
+                risk_dominant: true,
+            }]
+        );
+    }
+}
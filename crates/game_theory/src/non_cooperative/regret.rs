@@ -0,0 +1,91 @@
+use std::num::NonZeroUsize;
+
+use nalgebra::{DVector, RealField};
+
+use crate::non_cooperative::BiMatrixGame;
+
+impl<T: RealField + Copy> BiMatrixGame<T> {
+    /// Plays the game by [regret matching][1] for the given number of `iterations`
+    /// and returns the time-averaged mixed strategies of players A and B.
+    ///
+    /// Each player accumulates the regret of not having played each of its pure strategies
+    /// and then mixes the strategies proportionally to their positive regrets
+    /// (uniformly if there are none).
+    /// The expected payoffs are used instead of sampling the plays,
+    /// so that the result is deterministic.
+    ///
+    /// The average regrets vanish as `O(k^(-1/2))` after `k` iterations,
+    /// so the empirical distribution of the play converges to the set of coarse correlated equilibria.
+    /// In zero-sum games this means that the average strategies converge to the Nash equilibrium,
+    /// while in general games the product of the returned strategies need not be an equilibrium.
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Correlated_equilibrium
+    ///
+    /// # Panics
+    ///
+    /// If the game is empty.
+    #[must_use]
+    pub fn regret_matching(&self, iterations: NonZeroUsize) -> (DVector<T>, DVector<T>) {
+        assert!(!self.0.is_empty(), "the game should not be empty");
+        let (a, b) = self.split();
+        let b = b.transpose();
+
+        let mut a_regrets = DVector::<T>::zeros(a.nrows());
+        let mut b_regrets = DVector::<T>::zeros(a.ncols());
+        let mut a_strategies = DVector::<T>::zeros(a.nrows());
+        let mut b_strategies = DVector::<T>::zeros(a.ncols());
+        for _ in 0..iterations.get() {
+            let x = matched_strategy(&a_regrets);
+            let y = matched_strategy(&b_regrets);
+
+            let a_payoffs = &a * &y;
+            a_regrets += a_payoffs.add_scalar(-x.dot(&a_payoffs));
+            let b_payoffs = &b * &x;
+            b_regrets += b_payoffs.add_scalar(-y.dot(&b_payoffs));
+
+            a_strategies += x;
+            b_strategies += y;
+        }
+
+        let k = T::from_usize(iterations.get()).expect("the number of iterations should fit");
+        (a_strategies / k, b_strategies / k)
+    }
+}
+
+/// Mixes the strategies proportionally to their positive regrets.
+fn matched_strategy<T: RealField + Copy>(regrets: &DVector<T>) -> DVector<T> {
+    let positive = regrets.map(|regret| regret.max(T::zero()));
+    let total = positive.sum();
+    if total > T::zero() {
+        positive / total
+    } else {
+        let n = T::from_usize(regrets.len()).expect("the number of strategies should fit");
+        DVector::repeat(regrets.len(), T::one() / n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+    use crate::non_cooperative::Pair;
+
+    #[test]
+    fn regret_matching_in_zero_sum_game() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(2f64, -2.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.);
+        ]);
+        let (x, y) = game.regret_matching(NonZeroUsize::new(10_000).unwrap());
+        assert!((x.sum() - 1.).abs() < 1e-9);
+        assert!((y.sum() - 1.).abs() < 1e-9);
+
+        // neither player gains much by deviating from the average strategies
+        let zero_sum = game.to_zero_sum().unwrap();
+        let exploitability = zero_sum.exploitability(&x, &y);
+        assert!(exploitability < 5e-2, "{exploitability}");
+        assert!((x[0] - 0.4).abs() < 5e-2, "{x}");
+        assert!((y[0] - 0.4).abs() < 5e-2, "{y}");
+    }
+}
@@ -0,0 +1,130 @@
+use nalgebra::{DVector, RealField};
+
+use crate::non_cooperative::BiMatrixGame;
+
+/// The result of [solving](BiMatrixGame::solve) a bimatrix game.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameSolutionReport<T> {
+    /// The game has no strategies.
+    Empty,
+    /// The game has the given pure Nash equilibria as `(row, column)`.
+    Pure(Vec<(usize, usize)>),
+    /// The game has no pure Nash equilibria but has the mixed one.
+    Mixed {
+        /// The expected wins of players A and B.
+        values: (T, T),
+        /// The mixed strategy of player A.
+        x: DVector<T>,
+        /// The mixed strategy of player B.
+        y: DVector<T>,
+    },
+    /// The game has no pure Nash equilibria and the mixed one could not be found.
+    Unsolved,
+}
+
+impl<T: RealField + Copy> BiMatrixGame<T> {
+    /// Solves the game choosing the appropriate solution concept.
+    ///
+    /// The pure Nash equilibria are searched for first.
+    /// If there are none, the completely mixed equilibrium is found
+    /// by the [equalizing system](Self::mixed_balanced_strategies) or,
+    /// if it is singular in a `2×2` game, by the [best-response curves](Self::best_response_curves_2x2).
+    pub fn solve(&self) -> GameSolutionReport<T> {
+        if self.0.is_empty() {
+            return GameSolutionReport::Empty;
+        }
+
+        let pure: Vec<_> = self
+            .nash_equilibriums()
+            .map(|equilibrium| equilibrium.coordinate)
+            .collect();
+        if !pure.is_empty() {
+            return GameSolutionReport::Pure(pure);
+        }
+
+        let is_proper = |strategy: &DVector<T>| {
+            strategy
+                .iter()
+                .all(|probability| probability.is_finite() && *probability >= T::zero())
+        };
+        if let Some((values, (x, y))) = self.mixed_balanced_strategies() {
+            // both strategies are row matrices
+            let (x, y) = (
+                DVector::from_column_slice(x.as_slice()),
+                DVector::from_column_slice(y.as_slice()),
+            );
+            if is_proper(&x) && is_proper(&y) {
+                return GameSolutionReport::Mixed { values, x, y };
+            }
+        }
+
+        let Some((p, q)) = self
+            .best_response_curves_2x2()
+            .and_then(|curves| curves.mixed_equilibrium())
+        else {
+            return GameSolutionReport::Unsolved;
+        };
+        let x = DVector::from_column_slice(&[p, T::one() - p]);
+        let y = DVector::from_column_slice(&[q, T::one() - q]);
+        let (a, b) = self.split();
+        let values = (
+            (x.transpose() * a * &y)[(0, 0)],
+            (x.transpose() * b * &y)[(0, 0)],
+        );
+        GameSolutionReport::Mixed { values, x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+    use crate::non_cooperative::Pair;
+
+    #[test]
+    fn prisoners_dilemma_has_pure_equilibrium() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-5f64, -5.), Pair(0., -10.);
+            Pair(-10., 0.), Pair(-1., -1.);
+        ]);
+        assert_eq!(game.solve(), GameSolutionReport::Pure(vec![(0, 0)]));
+    }
+
+    #[test]
+    fn matching_pennies_is_mixed_only() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1f64, -1.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.);
+        ]);
+        assert_eq!(
+            game.solve(),
+            GameSolutionReport::Mixed {
+                values: (0., 0.),
+                x: dvector![0.5, 0.5],
+                y: dvector![0.5, 0.5],
+            }
+        );
+    }
+
+    #[test]
+    fn regular_mixed_equilibrium() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(2f64, -2.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.);
+        ]);
+        let GameSolutionReport::Mixed { values, x, y } = game.solve() else {
+            panic!("the game should have a mixed equilibrium");
+        };
+        assert!((values.0 - 0.2).abs() < 1e-9);
+        assert!((values.1 + 0.2).abs() < 1e-9);
+        assert!((x - dvector![0.4, 0.6]).norm() < 1e-9);
+        assert!((y - dvector![0.4, 0.6]).norm() < 1e-9);
+    }
+
+    #[test]
+    fn empty_game() {
+        let game = BiMatrixGame::<f64>::new(dmatrix![]);
+        assert_eq!(game.solve(), GameSolutionReport::Empty);
+    }
+}
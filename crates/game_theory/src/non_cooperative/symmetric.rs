@@ -0,0 +1,284 @@
+use itertools::Itertools;
+use nalgebra::{ComplexField, DMatrix, DVector, RealField};
+
+use crate::non_cooperative::BiMatrixGame;
+
+impl<T: RealField + Copy> BiMatrixGame<T> {
+    /// Finds the completely mixed strategy used by both players at the equilibrium
+    /// of a [symmetric](Self::is_symmetric) game.
+    ///
+    /// Since the payoff matrix of B is the transposed payoff matrix of A,
+    /// the single strategy `x` making the opponent indifferent between all of its strategies
+    /// is found by solving one bordered linear system `[A −1; 1ᵀ 0]·[x; v] = [0; 1]`
+    /// instead of inverting both matrices as done by the [general solution](Self::mixed_balanced_strategies).
+    /// Unlike solving `Ax = 1`, this also finds the equilibria whose value `v` is zero.
+    ///
+    /// Returns [`None`] if the game is not symmetric, the system is singular
+    /// or its solution is not a proper strategy, i.e. has negative or infinite probabilities.
+    #[must_use]
+    pub fn symmetric_equilibrium(&self) -> Option<DVector<T>> {
+        if self.0.is_empty() || !self.is_symmetric() {
+            return None;
+        }
+
+        let (a, _) = self.split();
+        let n = a.nrows();
+        let system = DMatrix::from_fn(n + 1, n + 1, |row, column| match (row < n, column < n) {
+            (true, true) => a[(row, column)],
+            (true, false) => -T::one(),
+            (false, true) => T::one(),
+            (false, false) => T::zero(),
+        });
+        let mut right = DVector::zeros(n + 1);
+        right[n] = T::one();
+        let solution = system.lu().solve(&right)?;
+
+        let strategy = solution.rows(0, n).into_owned();
+        strategy
+            .iter()
+            .all(|probability| probability.is_finite() && *probability >= T::zero())
+            .then_some(strategy)
+    }
+
+    /// Finds the evolutionarily stable strategies of a [symmetric](Self::is_symmetric) game.
+    ///
+    /// Following Maynard Smith and Price, the strategy `x` is evolutionarily stable
+    /// if for any mutant strategy `y ≠ x` either `u(x, x) > u(y, x)`,
+    /// or `u(x, x) = u(y, x)` and `u(x, y) > u(y, y)`, where `u` is the payoff of player A.
+    /// Thus, it is a symmetric Nash equilibrium which, by the second condition,
+    /// resists the invasion of the mutants being its alternative best replies.
+    ///
+    /// The candidates are the [symmetric equilibria](Self::symmetric_equilibrium)
+    /// of the subgames of all the supports, so the equilibria whose subgame is singular are not found.
+    /// The second condition is checked by the payoff matrix being negative definite
+    /// on the directions towards the best replies, which is exact unless the equilibrium
+    /// has best replies outside of its support.
+    ///
+    /// Returns no strategies if the game is not symmetric.
+    #[must_use]
+    pub fn evolutionarily_stable_strategies(&self) -> Vec<DVector<T>> {
+        if self.0.is_empty() || !self.is_symmetric() {
+            return Vec::new();
+        }
+
+        let (a, _) = self.split();
+        let n = a.nrows();
+        let tolerance = T::default_epsilon().sqrt();
+        (0..n)
+            .powerset()
+            .filter(|support| !support.is_empty())
+            .filter_map(|support| {
+                let partial = self.subgame(&support, &support).symmetric_equilibrium()?;
+                if partial.iter().any(|&probability| probability <= T::zero()) {
+                    return None;
+                }
+                let mut strategy = DVector::zeros(n);
+                for (&index, &probability) in support.iter().zip(&partial) {
+                    strategy[index] = probability;
+                }
+
+                // the strategy should be the best reply to itself
+                let payoffs = &a * &strategy;
+                let value = payoffs[support[0]];
+                if payoffs.iter().any(|&payoff| payoff > value + tolerance) {
+                    return None;
+                }
+
+                let best_replies: Vec<_> = (0..n)
+                    .filter(|&index| (payoffs[index] - value).abs() <= tolerance)
+                    .collect();
+                is_negative_definite_towards(&a, &best_replies).then_some(strategy)
+            })
+            .collect()
+    }
+}
+
+impl<T: ComplexField + Copy> BiMatrixGame<T> {
+    /// Simulates `steps` steps of the discrete-time replicator dynamics
+    /// of a [symmetric](Self::is_symmetric) game starting from the population state `start`.
+    ///
+    /// The frequency of each strategy grows proportionally to the excess of its fitness,
+    /// i.e. the payoff against the population, over the average fitness:
+    ///
+    /// ```latex
+    /// x_i' = x_i + dt * x_i * ((Ax)_i - x^T A x)
+    /// ```
+    ///
+    /// The states stay on the simplex as long as `dt` is small enough.
+    /// The trajectory includes the start state.
+    ///
+    /// # Panics
+    ///
+    /// If the game is not symmetric or the start state does not match its dimensions.
+    #[must_use]
+    pub fn replicator_dynamics(&self, start: DVector<T>, steps: usize, dt: T) -> Vec<DVector<T>> {
+        assert!(self.is_symmetric(), "the game should be symmetric");
+        let (a, _) = self.split();
+        assert_eq!(
+            start.len(),
+            a.nrows(),
+            "the state should have a frequency per strategy"
+        );
+
+        let mut trajectory = Vec::with_capacity(steps.saturating_add(1));
+        trajectory.push(start);
+        for _ in 0..steps {
+            let state = trajectory.last().expect("the trajectory is never empty");
+            let fitness = &a * state;
+            let average = state.dot(&fitness);
+            let next = state.zip_map(&fitness, |frequency, fitness| {
+                frequency + dt * frequency * (fitness - average)
+            });
+            trajectory.push(next);
+        }
+        trajectory
+    }
+}
+
+/// Checks if `zᵀ A z < 0` for all the non-zero `z` summing to zero
+/// which are only non-zero for the given strategies.
+fn is_negative_definite_towards<T: RealField + Copy>(a: &DMatrix<T>, strategies: &[usize]) -> bool {
+    let Some((&first, others)) = strategies.split_first() else {
+        return true;
+    };
+    // the basis of the directions from the first strategy towards the others
+    let basis = DMatrix::from_fn(a.nrows(), others.len(), |row, column| {
+        if row == others[column] {
+            T::one()
+        } else if row == first {
+            -T::one()
+        } else {
+            T::zero()
+        }
+    });
+    let form = basis.transpose() * (a + a.transpose()) * basis;
+    (-form).cholesky().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+    use crate::non_cooperative::Pair;
+
+    #[test]
+    fn matches_general_solution() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1f64, 1.), Pair(0., 2.), Pair(2., 0.);
+            Pair(2., 0.), Pair(1., 1.), Pair(0., 2.);
+            Pair(0., 2.), Pair(2., 0.), Pair(1., 1.);
+        ]);
+        let strategy = game.symmetric_equilibrium().unwrap();
+        assert!((&strategy - DVector::repeat(3, 1. / 3.)).norm() < 1e-12);
+
+        let (_, (x, y)) = game.mixed_balanced_strategies().unwrap();
+        assert!((strategy.transpose() - x).norm() < 1e-12);
+        assert!((strategy.transpose() - y).norm() < 1e-12);
+    }
+
+    #[test]
+    fn chicken() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(0f64, 0.), Pair(3., 1.);
+            Pair(1., 3.), Pair(2., 2.);
+        ]);
+        let strategy = game.symmetric_equilibrium().unwrap();
+        assert!((strategy - dvector![0.5, 0.5]).norm() < 1e-12);
+    }
+
+    #[test]
+    fn prisoners_dilemma_has_no_mixed_equilibrium() {
+        // the equalizing solution would give a negative probability to cooperation
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-5f64, -5.), Pair(0., -10.);
+            Pair(-10., 0.), Pair(-1., -1.);
+        ]);
+        assert_eq!(game.symmetric_equilibrium(), None);
+    }
+
+    #[test]
+    fn rock_paper_scissors_is_uniform() {
+        // the value is zero, so `A` itself is singular
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(0f64, 0.), Pair(-1., 1.), Pair(1., -1.);
+            Pair(1., -1.), Pair(0., 0.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.), Pair(0., 0.);
+        ]);
+        let strategy = game.symmetric_equilibrium().unwrap();
+        assert!((strategy - DVector::repeat(3, 1. / 3.)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn hawk_dove() {
+        // the value of the resource is `2` and the cost of the fight is `4`
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-1f64, -1.), Pair(2., 0.);
+            Pair(0., 2.), Pair(1., 1.);
+        ]);
+        let strategies = game.evolutionarily_stable_strategies();
+        assert_eq!(strategies.len(), 1);
+        assert!((&strategies[0] - dvector![0.5, 0.5]).norm() < 1e-12);
+    }
+
+    #[test]
+    fn replicator_dynamics_converges_to_ess() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(-1f64, -1.), Pair(2., 0.);
+            Pair(0., 2.), Pair(1., 1.);
+        ]);
+        let ess = game.evolutionarily_stable_strategies().remove(0);
+
+        for start in [dvector![0.6, 0.4], dvector![0.1, 0.9]] {
+            let trajectory = game.replicator_dynamics(start, 1000, 0.1);
+            assert_eq!(trajectory.len(), 1001);
+            for state in &trajectory {
+                assert!((state.sum() - 1.).abs() < 1e-9);
+            }
+            assert!((trajectory.last().unwrap() - &ess).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn replicator_dynamics_leaves_unstable_equilibrium() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1f64, 1.), Pair(0., 0.);
+            Pair(0., 0.), Pair(1., 1.);
+        ]);
+        let trajectory = game.replicator_dynamics(dvector![0.55, 0.45], 1000, 0.1);
+        assert!((trajectory.last().unwrap() - dvector![1., 0.]).norm() < 1e-9);
+    }
+
+    #[test]
+    fn coordination_game() {
+        // the mixed equilibrium is unstable
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(1f64, 1.), Pair(0., 0.);
+            Pair(0., 0.), Pair(1., 1.);
+        ]);
+        assert_eq!(
+            game.evolutionarily_stable_strategies(),
+            vec![dvector![1., 0.], dvector![0., 1.]]
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_has_none() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(0f64, 0.), Pair(-1., 1.), Pair(1., -1.);
+            Pair(1., -1.), Pair(0., 0.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.), Pair(0., 0.);
+        ]);
+        assert!(game.evolutionarily_stable_strategies().is_empty());
+    }
+
+    #[test]
+    fn asymmetric_game() {
+        let game = BiMatrixGame::new(dmatrix![
+            Pair(2f64, -2.), Pair(-1., 1.);
+            Pair(-1., 1.), Pair(1., -1.);
+        ]);
+        assert_eq!(game.symmetric_equilibrium(), None);
+        assert!(game.evolutionarily_stable_strategies().is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt,
     fmt::{Debug, Display, Formatter},
     io::{self, Write},
+    iter::Sum,
     num::{NonZeroU8, Wrapping},
+    ops::Sub,
 };
 
 use rand::{
@@ -23,6 +25,8 @@ struct Loc {
 struct Node<T> {
     loc: Loc,
     prize: Option<Prize<T>>,
+    /// The zero-based strategy chosen at this node by the reduction.
+    choice: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -35,6 +39,14 @@ pub struct BackwardInductionGame<T> {
     layers: Vec<Layer<T>>,
 }
 
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+pub enum InvalidDiscount {
+    #[error("expected a discount factor for each of {expected} players, got {actual}")]
+    Count { expected: usize, actual: usize },
+    #[error("discount factor {factor} of player {player} is not in (0, 1]")]
+    OutOfRange { player: Player, factor: f64 },
+}
+
 impl<T> BackwardInductionGame<T> {
     pub fn reduce(&mut self, mut out: impl Write) -> io::Result<()>
     where
@@ -50,21 +62,17 @@ impl<T> BackwardInductionGame<T> {
             writeln!(out, "# Iteration #{iteration}")?;
             writeln!(out)?;
 
-            let mut wins = HashMap::<usize, Vec<Prize<T>>>::new();
+            let mut wins = HashMap::<usize, Vec<(usize, Prize<T>)>>::new();
             for node in &self.layers[layer].nodes {
                 wins.entry(node.loc.parent)
                     .or_default()
-                    .push(node.prize.clone().unwrap());
+                    .push((node.loc.strat - 1, node.prize.clone().unwrap()));
             }
             for (parent_idx, prizes) in wins {
                 let parent = &mut self.layers[layer - 1].nodes[parent_idx];
-                let parent_player = parent.loc.player.0;
-                parent.prize = Some(
-                    prizes
-                        .into_iter()
-                        .max_by_key(|prize| prize.0[parent_player])
-                        .unwrap(),
-                )
+                let (choice, prize) = best_choice(prizes, parent.loc.player);
+                parent.prize = Some(prize);
+                parent.choice = Some(choice);
             }
 
             self.print_current(&mut out)?;
@@ -82,12 +90,60 @@ impl<T> BackwardInductionGame<T> {
     where
         T: SampleUniform,
     {
+        Self::with_prizes(depth, players, |_| {
+            Prize(
+                players
+                    .iter()
+                    .map(|_| generator.gen_range(range.clone()))
+                    .collect(),
+            )
+        })
+    }
+
+    /// Creates the tree in which the players move in turns
+    /// and the prize of each leaf is computed by `prize`.
+    ///
+    /// The `prize` receives the path to the leaf,
+    /// i.e. the zero-based strategies chosen at each of the `depth` moves.
+    ///
+    /// The shape of the tree is fully determined by the `depth` and the `players`,
+    /// so no random generator is taken: a `prize` needing randomness can capture one,
+    /// as done by [`random`](Self::random).
+    pub fn with_prizes(
+        depth: NonZeroU8,
+        players: &[NonZeroU8],
+        prize: impl FnMut(&[usize]) -> Prize<T>,
+    ) -> Option<Self> {
         if players.is_empty() {
             return None;
         }
 
         let depth = depth.get() as usize;
-        let mut layers = Vec::with_capacity(depth);
+        let layout: Vec<_> = (0..=depth)
+            .map(|layer| Player(layer % players.len()))
+            .collect();
+        let branching: Vec<_> = (0..depth)
+            .map(|layer| players[layer % players.len()].get() as usize)
+            .collect();
+
+        Some(Self::from_layout(&layout, &branching, prize))
+    }
+
+    /// Creates the tree in which all the nodes of a layer belong to the same player
+    /// and have the same number of strategies.
+    ///
+    /// The `layout` defines the player of each layer (including the leaves)
+    /// and `branching` defines the number of strategies available in each non-leaf layer.
+    /// The prizes of the leaves are produced by `prize`
+    /// which receives the (zero-based) strategies chosen on the way to the leaf.
+    fn from_layout(
+        layout: &[Player],
+        branching: &[usize],
+        mut prize: impl FnMut(&[usize]) -> Prize<T>,
+    ) -> Self {
+        debug_assert_eq!(layout.len(), branching.len() + 1);
+
+        let mut layers = Vec::with_capacity(layout.len());
 
         let mut uid = 0;
         let mut layer_size = 1usize;
@@ -95,54 +151,300 @@ impl<T> BackwardInductionGame<T> {
             nodes: vec![Node {
                 loc: Loc {
                     uid,
-                    player: Player(0),
+                    player: layout[0],
                     strat: 0,
                     parent: 0,
                 },
                 prize: None,
+                choice: None,
             }],
         });
 
-        for layer in 0..depth {
-            let src_player = layer % players.len();
-
-            let player_paths = players[src_player].get() as usize;
-
+        for (&player, &player_paths) in layout[1..].iter().zip(branching) {
             layer_size *= player_paths;
             let mut nodes = Vec::with_capacity(layer_size);
 
-            let mut parent_index = 0;
             for at_layer_index in 0..layer_size {
                 uid += 1;
 
-                let strat = at_layer_index % player_paths;
-                if strat == 0 {
-                    parent_index += 1;
-                }
-
                 nodes.push(Node {
                     loc: Loc {
                         uid,
-                        player: Player((src_player + 1) % players.len()),
-                        strat: strat + 1,
-                        parent: parent_index - 1,
+                        player,
+                        strat: at_layer_index % player_paths + 1,
+                        parent: at_layer_index / player_paths,
                     },
                     prize: None,
+                    choice: None,
                 });
             }
             layers.push(Layer { nodes });
         }
 
-        for node in &mut layers.last_mut().unwrap().nodes {
-            node.prize = Some(Prize(
-                players
+        let mut path = vec![0; branching.len()];
+        for (index, node) in layers.last_mut().unwrap().nodes.iter_mut().enumerate() {
+            let mut index = index;
+            for (strategy, &player_paths) in path.iter_mut().zip(branching).rev() {
+                *strategy = index % player_paths;
+                index /= player_paths;
+            }
+            node.prize = Some(prize(&path));
+        }
+
+        Self { layers }
+    }
+
+    /// Computes the equilibrium prize discounted by the depth of the leaves,
+    /// where `discount` holds the factor by which the prize of each player is discounted
+    /// on every move, so that a prize realized at depth `d` is worth `factor^d` of it at the root.
+    ///
+    /// Since all the leaves of the tree are at the same depth, discounting scales
+    /// the wins of each player by a constant and does not affect the equilibrium path,
+    /// only the present value of the equilibrium prize.
+    pub fn discounted_equilibrium_prize(
+        &self,
+        discount: &[f64],
+    ) -> Result<Vec<f64>, InvalidDiscount>
+    where
+        T: Ord + Copy + Into<f64>,
+    {
+        let Prize(prize) = self.equilibrium_prize();
+        if discount.len() != prize.len() {
+            return Err(InvalidDiscount::Count {
+                expected: prize.len(),
+                actual: discount.len(),
+            });
+        }
+        if let Some((player, &factor)) = discount
+            .iter()
+            .enumerate()
+            .find(|(_, &factor)| !(factor > 0. && factor <= 1.))
+        {
+            return Err(InvalidDiscount::OutOfRange {
+                player: Player(player),
+                factor,
+            });
+        }
+
+        let depth = (self.layers.len() - 1) as i32;
+        Ok(prize
+            .into_iter()
+            .zip(discount)
+            .map(|(win, factor)| win.into() * factor.powi(depth))
+            .collect())
+    }
+
+    /// Lists the strategies chosen by the players in each of the non-leaf nodes
+    /// as `(player, node uid, zero-based strategy)`.
+    ///
+    /// The strategies are only known after the tree is [reduced](Self::reduce).
+    pub fn strategy_table(&self) -> Vec<(Player, usize, usize)> {
+        self.layers
+            .iter()
+            .flat_map(|layer| &layer.nodes)
+            .filter_map(|node| Some((node.loc.player, node.loc.uid, node.choice?)))
+            .collect()
+    }
+
+    /// Computes how much the `player` gains by committing to its first move,
+    /// i.e. by making it before all the other moves.
+    ///
+    /// This is the difference between the player's equilibrium payoffs
+    /// in the game where its first move is made at the root and in the current game.
+    pub fn value_of_commitment(&self, player: Player) -> T
+    where
+        T: Ord + Copy + Sub<Output = T>,
+    {
+        let (layout, branching) = self.layout();
+        let current = self.equilibrium_prize();
+
+        // if the player never moves, the game stays the same
+        let first_move = layout[..branching.len()]
+            .iter()
+            .position(|&layer_player| layer_player == player)
+            .unwrap_or(0);
+
+        // the first move of the player is moved to the root preserving the order of the others
+        let order: Vec<_> = std::iter::once(first_move)
+            .chain((0..branching.len()).filter(|&layer| layer != first_move))
+            .collect();
+        let committed_layout: Vec<_> = order
+            .iter()
+            .map(|&layer| layout[layer])
+            .chain(layout.last().copied())
+            .collect();
+        let committed_branching: Vec<_> = order.iter().map(|&layer| branching[layer]).collect();
+
+        let leaves = &self
+            .layers
+            .last()
+            .expect("there is always a root layer")
+            .nodes;
+        let mut original_path = vec![0; branching.len()];
+        let committed = Self::from_layout(&committed_layout, &committed_branching, |path| {
+            for (&layer, &strategy) in order.iter().zip(path) {
+                original_path[layer] = strategy;
+            }
+            let index = original_path
+                .iter()
+                .zip(&branching)
+                .fold(0, |index, (strategy, paths)| index * paths + strategy);
+            leaves[index]
+                .prize
+                .clone()
+                .expect("leaves always have prizes")
+        })
+        .equilibrium_prize();
+
+        committed.0[player.0] - current.0[player.0]
+    }
+
+    /// Computes the social welfare of the equilibrium, i.e. the sum of the players' wins.
+    ///
+    /// # Panics
+    ///
+    /// If the tree has not been [reduced](Self::reduce) yet.
+    pub fn equilibrium_welfare(&self) -> T
+    where
+        T: Copy + Sum,
+    {
+        self.root_prize().0.iter().copied().sum()
+    }
+
+    /// Checks if the equilibrium is Pareto-optimal among all the leaves of the tree.
+    ///
+    /// # Panics
+    ///
+    /// If the tree has not been [reduced](Self::reduce) yet.
+    pub fn is_pareto_optimal_equilibrium(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        let Prize(equilibrium) = self.root_prize();
+        self.layers
+            .last()
+            .expect("there is always a root layer")
+            .nodes
+            .iter()
+            .filter_map(|node| node.prize.as_ref())
+            .all(|Prize(other)| {
+                let dominates = other
                     .iter()
-                    .map(|_| generator.gen_range(range.clone()))
-                    .collect(),
-            ));
+                    .zip(equilibrium)
+                    .all(|(other, win)| other >= win)
+                    && other
+                        .iter()
+                        .zip(equilibrium)
+                        .any(|(other, win)| other > win);
+                !dominates
+            })
+    }
+
+    fn root_prize(&self) -> &Prize<T> {
+        self.layers[0].nodes[0]
+            .prize
+            .as_ref()
+            .expect("the tree should be reduced")
+    }
+
+    /// Computes the security value of each non-leaf node, i.e. the payoff
+    /// which the player moving at the node can guarantee in its subtree
+    /// regardless of the choices of the other players.
+    ///
+    /// Unlike [reduction](Self::reduce), which assumes that each player maximizes its own payoff,
+    /// this assumes that all the other players act against the player.
+    pub fn security_values(&self) -> HashMap<usize, T>
+    where
+        T: Ord + Copy,
+    {
+        let mut values = HashMap::new();
+
+        // the security values of all players in the nodes of the current layer
+        let mut securities: Vec<_> = self
+            .layers
+            .last()
+            .expect("there is always a root layer")
+            .nodes
+            .iter()
+            .map(|node| node.prize.clone().expect("leaves always have prizes").0)
+            .collect();
+
+        for layer in self.layers[..self.layers.len() - 1].iter().rev() {
+            let player_paths = securities.len() / layer.nodes.len();
+            let mut children = securities.into_iter();
+            securities = layer
+                .nodes
+                .iter()
+                .map(|node| {
+                    let Player(mover) = node.loc.player;
+                    let security = children
+                        .by_ref()
+                        .take(player_paths)
+                        .reduce(|mut security, child| {
+                            for (player, (security, child)) in
+                                security.iter_mut().zip(child).enumerate()
+                            {
+                                *security = if player == mover {
+                                    (*security).max(child)
+                                } else {
+                                    (*security).min(child)
+                                };
+                            }
+                            security
+                        })
+                        .expect("there is at least one child");
+                    values.insert(node.loc.uid, security[mover]);
+                    security
+                })
+                .collect();
+        }
+
+        values
+    }
+
+    /// Gets the players of the layers and the number of strategies in each non-leaf layer.
+    fn layout(&self) -> (Vec<Player>, Vec<usize>) {
+        let layout = self
+            .layers
+            .iter()
+            .map(|layer| layer.nodes[0].loc.player)
+            .collect();
+        let branching = self
+            .layers
+            .windows(2)
+            .map(|layers| layers[1].nodes.len() / layers[0].nodes.len())
+            .collect();
+        (layout, branching)
+    }
+
+    /// Computes the prize at the root of the game without modifying the tree.
+    fn equilibrium_prize(&self) -> Prize<T>
+    where
+        T: Ord + Copy,
+    {
+        let mut prizes: Vec<_> = self
+            .layers
+            .last()
+            .expect("there is always a root layer")
+            .nodes
+            .iter()
+            .map(|node| node.prize.clone().expect("leaves always have prizes"))
+            .collect();
+
+        for layer in self.layers[..self.layers.len() - 1].iter().rev() {
+            let player_paths = prizes.len() / layer.nodes.len();
+            let mut children = prizes.into_iter();
+            prizes = layer
+                .nodes
+                .iter()
+                .map(|node| {
+                    let choices = children.by_ref().take(player_paths).enumerate();
+                    best_choice(choices, node.loc.player).1
+                })
+                .collect();
         }
 
-        Some(Self { layers })
+        prizes.pop().expect("there is exactly one root")
     }
 
     pub fn print_current(&self, out: &mut impl Write) -> io::Result<()>
@@ -194,18 +496,16 @@ impl<T> BackwardInductionGame<T> {
                 if let Some(prize) = &cur.prize {
                     let uid = cur.loc.uid;
                     let parent_uid = &self.layers[layer - 1].nodes[cur.loc.parent].loc.uid;
-                    let color = if Some(prize) == root_win {
-                        let parent_color = parents
-                            .get_mut(parent_uid)
-                            .unwrap_or_else(|| panic!("Parent {parent_uid} for {uid}"));
-
-                        let color = *parent_color;
-                        // A big prime number to mix colors.
-                        *parent_color *= 82_589_933;
-                        parents.insert(uid, color);
-                        Some(color.0)
-                    } else {
-                        None
+                    // equal prizes may also be reached outside the optimal path
+                    let color = match parents.get_mut(parent_uid) {
+                        Some(parent_color) if Some(prize) == root_win => {
+                            let color = *parent_color;
+                            // A big prime number to mix colors.
+                            *parent_color *= 82_589_933;
+                            parents.insert(uid, color);
+                            Some(color.0)
+                        }
+                        _ => None,
                     };
 
                     wins.push(Win {
@@ -220,14 +520,35 @@ impl<T> BackwardInductionGame<T> {
             Win::commit(&wins, out, &mut link_id)?;
         }
 
+        // tint the decision nodes by the players moving in them
+        let mut player_nodes = BTreeMap::<usize, Vec<String>>::new();
+        for node in self.layers[..max_layer]
+            .iter()
+            .flat_map(|layer| &layer.nodes)
+        {
+            player_nodes
+                .entry(node.loc.player.0)
+                .or_default()
+                .push(node.loc.uid.to_string());
+        }
+        for (player, uids) in player_nodes {
+            let fill = PLAYER_FILLS[player % PLAYER_FILLS.len()];
+            let player = Player(player);
+            writeln!(out, "    classDef player{player} fill:#{fill:06x}")?;
+            writeln!(out, "    class {} player{player}", uids.join(","))?;
+        }
+
         writeln!(out, "```")?;
 
         Ok(())
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-struct Player(usize);
+/// Light fill colors of the nodes of the players.
+const PLAYER_FILLS: [u32; 6] = [0xFFD6D6, 0xD6E4FF, 0xD6FFD9, 0xFFF3C4, 0xECD6FF, 0xC4FFF6];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Player(pub usize);
 
 impl Display for Player {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -238,8 +559,20 @@ impl Display for Player {
     }
 }
 
+/// Selects the strategy whose prize is the best for the given `player`.
+fn best_choice<T: Ord + Copy>(
+    choices: impl IntoIterator<Item = (usize, Prize<T>)>,
+    player: Player,
+) -> (usize, Prize<T>) {
+    choices
+        .into_iter()
+        .max_by_key(|(_, prize)| prize.0[player.0])
+        .expect("there is at least one choice")
+}
+
+/// The wins of all the players, indexed by the players.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Prize<T>(Vec<T>);
+pub struct Prize<T>(pub Vec<T>);
 
 impl<T: Display> Display for Prize<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -302,3 +635,156 @@ impl<T: Ord + Copy + Display> Win<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The battle of sexes in which player A moves first.
+    fn battle_of_sexes() -> BackwardInductionGame<i32> {
+        BackwardInductionGame::from_layout(&[Player(0), Player(1), Player(0)], &[2, 2], |path| {
+            Prize(match path {
+                [0, 0] => vec![2, 1],
+                [1, 1] => vec![1, 2],
+                _ => vec![0, 0],
+            })
+        })
+    }
+
+    #[test]
+    fn equilibrium_prize_matches_reduce() {
+        let mut game = battle_of_sexes();
+        let prize = game.equilibrium_prize();
+        game.reduce(io::sink()).unwrap();
+        assert_eq!(game.layers[0].nodes[0].prize, Some(prize));
+    }
+
+    #[test]
+    fn commitment_helps_the_follower() {
+        let game = battle_of_sexes();
+        assert_eq!(game.equilibrium_prize(), Prize(vec![2, 1]));
+        assert_eq!(game.value_of_commitment(Player(1)), 1);
+        assert_eq!(game.value_of_commitment(Player(0)), 0);
+    }
+
+    #[test]
+    fn prizes_by_path() {
+        let players = [NonZeroU8::new(2).unwrap(), NonZeroU8::new(3).unwrap()];
+        let mut game =
+            BackwardInductionGame::with_prizes(NonZeroU8::new(3).unwrap(), &players, |path| {
+                let sum = path.iter().sum::<usize>() as i32;
+                Prize(vec![sum, -sum])
+            })
+            .unwrap();
+
+        let leaves = &game.layers[3].nodes;
+        assert_eq!(leaves.len(), 2 * 3 * 2);
+        assert_eq!(leaves[0].prize, Some(Prize(vec![0, 0])));
+        // path [0, 2, 1]
+        assert_eq!(leaves[5].prize, Some(Prize(vec![3, -3])));
+        // path [1, 2, 1]
+        assert_eq!(leaves[11].prize, Some(Prize(vec![4, -4])));
+
+        game.reduce(io::sink()).unwrap();
+        // A maximizes the sum, B minimizes it
+        assert_eq!(game.layers[0].nodes[0].prize, Some(Prize(vec![2, -2])));
+    }
+
+    #[test]
+    fn no_players() {
+        let game =
+            BackwardInductionGame::<i32>::with_prizes(NonZeroU8::MIN, &[], |_| unreachable!());
+        assert!(game.is_none());
+    }
+
+    #[test]
+    fn strategy_table() {
+        let mut game = battle_of_sexes();
+        assert_eq!(game.strategy_table(), vec![]);
+
+        game.reduce(io::sink()).unwrap();
+        assert_eq!(
+            game.strategy_table(),
+            vec![(Player(0), 0, 0), (Player(1), 1, 0), (Player(1), 2, 1)]
+        );
+    }
+
+    #[test]
+    fn players_are_styled() {
+        let mut out = vec![];
+        battle_of_sexes().print_current(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("    classDef playerA fill:#ffd6d6\n    class 0 playerA\n"));
+        assert!(out.contains("    classDef playerB fill:#d6e4ff\n    class 1,2 playerB\n"));
+    }
+
+    #[test]
+    fn security_values_are_pessimistic() {
+        let mut game = battle_of_sexes();
+        assert_eq!(
+            game.security_values(),
+            HashMap::from([(0, 0), (1, 1), (2, 2)])
+        );
+
+        // while the backward induction expects B to cooperate
+        game.reduce(io::sink()).unwrap();
+        assert_eq!(game.layers[0].nodes[0].prize, Some(Prize(vec![2, 1])));
+    }
+
+    #[test]
+    fn centipede_equilibrium_is_inefficient() {
+        let players = [NonZeroU8::new(2).unwrap(), NonZeroU8::new(2).unwrap()];
+        // strategy `0` takes the pot ending the game, strategy `1` passes
+        let mut game =
+            BackwardInductionGame::with_prizes(NonZeroU8::new(4).unwrap(), &players, |path| {
+                Prize(match path.iter().position(|&strategy| strategy == 0) {
+                    Some(0) => vec![1, 0],
+                    Some(1) => vec![0, 2],
+                    Some(2) => vec![3, 1],
+                    Some(_) => vec![2, 4],
+                    None => vec![5, 3],
+                })
+            })
+            .unwrap();
+        game.reduce(io::sink()).unwrap();
+
+        assert_eq!(game.root_prize(), &Prize(vec![1, 0]));
+        assert_eq!(game.equilibrium_welfare(), 1);
+        assert!(!game.is_pareto_optimal_equilibrium());
+
+        let mut game = battle_of_sexes();
+        game.reduce(io::sink()).unwrap();
+        assert_eq!(game.equilibrium_welfare(), 3);
+        assert!(game.is_pareto_optimal_equilibrium());
+    }
+
+    #[test]
+    fn discount_scales_equilibrium_prize() {
+        let game = battle_of_sexes();
+        assert_eq!(
+            game.discounted_equilibrium_prize(&[0.5]).unwrap_err(),
+            InvalidDiscount::Count {
+                expected: 2,
+                actual: 1
+            }
+        );
+        assert_eq!(
+            game.discounted_equilibrium_prize(&[0.5, 0.]).unwrap_err(),
+            InvalidDiscount::OutOfRange {
+                player: Player(1),
+                factor: 0.
+            }
+        );
+        assert!(game.discounted_equilibrium_prize(&[1., 1.5]).is_err());
+
+        assert_eq!(
+            game.discounted_equilibrium_prize(&[0.5, 1.]),
+            Ok(vec![0.5, 1.])
+        );
+        assert_eq!(
+            game.discounted_equilibrium_prize(&[1., 1.]),
+            Ok(vec![2., 1.])
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Linear programming via the [simplex method][1].
+//!
+//! [1]: https://en.wikipedia.org/wiki/Simplex_algorithm
+
+use nalgebra::{DMatrix, DVector};
+
+/// Tolerance used to compare the values of the simplex tableau with zero.
+const EPSILON: f64 = 1e-9;
+
+/// Optimal solution of a linear program.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// The optimal value of the objective function.
+    pub value: f64,
+    /// The optimal values of the variables.
+    pub x: DVector<f64>,
+    /// The optimal values of the dual variables, one per constraint.
+    pub dual: DVector<f64>,
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    #[error("the constraints are infeasible")]
+    Infeasible,
+    #[error("the objective function is unbounded")]
+    Unbounded,
+}
+
+/// Maximizes `cᵀx` subject to `Ax <= b` and `x >= 0`.
+///
+/// The right-hand side `b` may contain negative values,
+/// in which case the feasible basis is found by the two-phase method.
+/// Bland's rule is used to prevent cycling.
+///
+/// # Panics
+///
+/// If the dimensions of `c`, `a` and `b` do not agree.
+pub fn maximize(c: &DVector<f64>, a: &DMatrix<f64>, b: &DVector<f64>) -> Result<Solution, Error> {
+    let (rows, variables) = a.shape();
+    assert_eq!(c.len(), variables, "c should have a value per variable");
+    assert_eq!(b.len(), rows, "b should have a value per constraint");
+
+    let artificial_rows: Vec<_> = (0..rows).filter(|&row| b[row] < 0.).collect();
+    let first_artificial = variables + rows;
+    let columns = first_artificial + artificial_rows.len();
+
+    // the last row is the objective, the last column is the right-hand side
+    let mut tableau = Tableau {
+        cells: DMatrix::zeros(rows + 1, columns + 1),
+        basis: (variables..first_artificial).collect(),
+    };
+    for row in 0..rows {
+        let sign = if b[row] < 0. { -1. } else { 1. };
+        for column in 0..variables {
+            tableau.cells[(row, column)] = sign * a[(row, column)];
+        }
+        tableau.cells[(row, variables + row)] = sign;
+        tableau.cells[(row, columns)] = sign * b[row];
+    }
+    for (index, &row) in artificial_rows.iter().enumerate() {
+        tableau.cells[(row, first_artificial + index)] = 1.;
+        tableau.basis[row] = first_artificial + index;
+    }
+
+    if !artificial_rows.is_empty() {
+        // phase 1: maximize the negated sum of the artificial variables
+        let mut phase_1 = DVector::zeros(columns);
+        phase_1
+            .rows_mut(first_artificial, artificial_rows.len())
+            .fill(-1.);
+        tableau.set_objective(&phase_1);
+        tableau.run(columns)?;
+        if tableau.value() < -EPSILON {
+            return Err(Error::Infeasible);
+        }
+        tableau.drive_out_artificial(first_artificial);
+    }
+
+    // phase 2: the artificial variables are never allowed to enter the basis again
+    let mut phase_2 = DVector::zeros(columns);
+    phase_2.rows_mut(0, variables).copy_from(c);
+    tableau.set_objective(&phase_2);
+    tableau.run(first_artificial)?;
+
+    let mut x = DVector::zeros(variables);
+    for (row, &basic) in tableau.basis.iter().enumerate() {
+        if basic < variables {
+            x[basic] = tableau.cells[(row, columns)];
+        }
+    }
+    Ok(Solution {
+        value: tableau.value(),
+        x,
+        dual: DVector::from_fn(rows, |row, _| tableau.cells[(rows, variables + row)]),
+    })
+}
+
+struct Tableau {
+    cells: DMatrix<f64>,
+    /// The basic variable of each constraint row.
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn objective_row(&self) -> usize {
+        self.cells.nrows() - 1
+    }
+
+    fn rhs_column(&self) -> usize {
+        self.cells.ncols() - 1
+    }
+
+    fn value(&self) -> f64 {
+        self.cells[(self.objective_row(), self.rhs_column())]
+    }
+
+    /// Fills the objective row with the reduced costs of the objective `c`.
+    fn set_objective(&mut self, c: &DVector<f64>) {
+        let objective = self.objective_row();
+        for column in 0..=self.rhs_column() {
+            let basic_cost: f64 = self
+                .basis
+                .iter()
+                .enumerate()
+                .map(|(row, &basic)| c[basic] * self.cells[(row, column)])
+                .sum();
+            let cost = c.get(column).copied().unwrap_or(0.);
+            self.cells[(objective, column)] = basic_cost - cost;
+        }
+    }
+
+    /// Performs the simplex iterations letting only the first `allowed` columns enter the basis.
+    fn run(&mut self, allowed: usize) -> Result<(), Error> {
+        let objective = self.objective_row();
+        let rhs = self.rhs_column();
+        loop {
+            let Some(entering) =
+                (0..allowed).find(|&column| self.cells[(objective, column)] < -EPSILON)
+            else {
+                return Ok(());
+            };
+
+            let leaving = (0..objective)
+                .filter(|&row| self.cells[(row, entering)] > EPSILON)
+                .map(|row| (row, self.cells[(row, rhs)] / self.cells[(row, entering)]))
+                .min_by(|(left_row, left), (right_row, right)| {
+                    left.total_cmp(right)
+                        .then(self.basis[*left_row].cmp(&self.basis[*right_row]))
+                })
+                .map(|(row, _)| row)
+                .ok_or(Error::Unbounded)?;
+
+            self.pivot(leaving, entering);
+        }
+    }
+
+    /// Replaces the artificial basic variables (which are zero after phase 1) with the regular ones.
+    fn drive_out_artificial(&mut self, first_artificial: usize) {
+        for row in 0..self.objective_row() {
+            if self.basis[row] < first_artificial {
+                continue;
+            }
+            // a row without such columns is redundant and may keep its artificial variable
+            if let Some(column) =
+                (0..first_artificial).find(|&column| self.cells[(row, column)].abs() > EPSILON)
+            {
+                self.pivot(row, column);
+            }
+        }
+    }
+
+    fn pivot(&mut self, row: usize, column: usize) {
+        let pivot = self.cells[(row, column)];
+        self.cells.row_mut(row).scale_mut(1. / pivot);
+        let pivot_row = self.cells.row(row).clone_owned();
+        for other in 0..self.cells.nrows() {
+            if other != row {
+                let factor = self.cells[(other, column)];
+                if factor != 0. {
+                    let updated = self.cells.row(other) - &pivot_row * factor;
+                    self.cells.set_row(other, &updated);
+                }
+            }
+        }
+        self.basis[row] = column;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+
+    #[test]
+    fn maximize_with_feasible_origin() {
+        // max 3x + 5y: x <= 4, 2y <= 12, 3x + 2y <= 18
+        let solution = maximize(
+            &dvector![3., 5.],
+            &dmatrix![
+                1., 0.;
+                0., 2.;
+                3., 2.;
+            ],
+            &dvector![4., 12., 18.],
+        )
+        .unwrap();
+
+        assert!((solution.value - 36.).abs() < EPSILON);
+        assert!((solution.x - dvector![2., 6.]).norm() < EPSILON);
+        assert!((solution.dual - dvector![0., 1.5, 1.]).norm() < EPSILON);
+    }
+
+    #[test]
+    fn maximize_with_infeasible_origin() {
+        // max -x - y: x + y >= 2, x <= 3
+        let solution = maximize(
+            &dvector![-1., -1.],
+            &dmatrix![
+                -1., -1.;
+                1., 0.;
+            ],
+            &dvector![-2., 3.],
+        )
+        .unwrap();
+
+        assert!((solution.value + 2.).abs() < EPSILON);
+        assert!((solution.x.sum() - 2.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn infeasible_and_unbounded() {
+        // x <= 1, x >= 2
+        assert_eq!(
+            maximize(&dvector![1.], &dmatrix![1.; -1.], &dvector![1., -2.]),
+            Err(Error::Infeasible)
+        );
+        // max x: -x <= 1
+        assert_eq!(
+            maximize(&dvector![1.], &dmatrix![-1.], &dvector![1.]),
+            Err(Error::Unbounded)
+        );
+    }
+}
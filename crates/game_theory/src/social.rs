@@ -0,0 +1,460 @@
+//! Opinion dynamics in social networks used for modelling [informational warfare][1].
+//!
+//! The agents are connected by the row-stochastic influence matrix `A`,
+//! so that their opinions evolve as `x(k+1) = A·x(k)` until a consensus is reached.
+//!
+//! [1]: https://en.wikipedia.org/wiki/DeGroot_learning
+
+use std::cmp::Ordering;
+
+use nalgebra::{DMatrix, DVector, Matrix2, Vector2};
+use tracing::debug;
+
+/// The outcome of the [opinion dynamics simulation](simulate).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Consensus {
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The resulting opinions of the agents.
+    pub opinions: DVector<f64>,
+    history: Option<Vec<DVector<f64>>>,
+}
+
+impl Consensus {
+    /// Gets the opinions of the agents on each of the iterations starting from the initial ones,
+    /// if they were [recorded](simulate_with_history).
+    pub fn opinion_history(&self) -> Option<&[DVector<f64>]> {
+        self.history.as_deref()
+    }
+}
+
+/// The verdict of the informational warfare given by the [consensus](warfare_outcome).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarfareOutcome {
+    /// The consensus is closer to the target of the first player.
+    FirstPlayerWins,
+    /// The consensus is closer to the target of the second player.
+    SecondPlayerWins,
+    /// The consensus is equally far from the targets of both players.
+    Draw,
+}
+
+/// Simulates the evolution of the opinions `x` under the influence matrix `a`
+/// until the opinions differ by no more than `epsilon`.
+pub fn simulate(a: &DMatrix<f64>, x: DVector<f64>, epsilon: f64) -> Consensus {
+    simulate_recording(a, x, epsilon, None)
+}
+
+/// Same as [`simulate`] but also records the opinions on each iteration.
+///
+/// Note that this stores the whole trajectory which takes `O(iterations·n)` memory.
+pub fn simulate_with_history(a: &DMatrix<f64>, x: DVector<f64>, epsilon: f64) -> Consensus {
+    simulate_recording(a, x, epsilon, Some(Vec::new()))
+}
+
+/// Runs the [simulation](simulate) for each value of some parameter
+/// to study how the consensus depends on it.
+///
+/// For each of the `parameters` the initial opinions `x` are first modified by `adjust`,
+/// e.g. by assigning the opinion of a player to the [agents](optimal_agents) it controls,
+/// and the resulting consensus is paired with the parameter.
+pub fn sensitivity_sweep<P>(
+    a: &DMatrix<f64>,
+    x: &DVector<f64>,
+    epsilon: f64,
+    parameters: impl IntoIterator<Item = P>,
+    mut adjust: impl FnMut(&P, &mut DVector<f64>),
+) -> Vec<(P, f64)> {
+    parameters
+        .into_iter()
+        .map(|parameter| {
+            let mut x = x.clone();
+            adjust(&parameter, &mut x);
+            let consensus = simulate(a, x, epsilon).opinions.mean();
+            (parameter, consensus)
+        })
+        .collect()
+}
+
+/// Computes the influence weights of the agents in the consensus,
+/// i.e. the normalized left [Perron eigenvector][1] `w` of the influence matrix, such that `wᵀA = wᵀ`.
+///
+/// The consensus reached from the opinions `x` is then `wᵀx`.
+///
+/// Returns [`None`] if the matrix is not square or is not [primitive][2],
+/// in which case the consensus is not guaranteed.
+///
+/// [1]: https://en.wikipedia.org/wiki/Perron%E2%80%93Frobenius_theorem
+/// [2]: https://en.wikipedia.org/wiki/Perron%E2%80%93Frobenius_theorem#Primitive_matrices
+pub fn stationary_weights(a: &DMatrix<f64>) -> Option<DVector<f64>> {
+    if a.is_empty() || !a.is_square() || !is_primitive(a) {
+        return None;
+    }
+
+    // solve `(Aᵀ - I)w = 0` with one of the (linearly dependent) equations replaced by `Σw = 1`
+    let n = a.nrows();
+    let mut system = a.transpose() - DMatrix::identity(n, n);
+    system.row_mut(n - 1).fill(1.);
+    let mut right = DVector::zeros(n);
+    right[n - 1] = 1.;
+
+    system.lu().solve(&right)
+}
+
+/// Computes how much the final opinion of each agent depends on the others,
+/// i.e. the off-diagonal mass `1 - wᵢ` of its row in the limiting influence matrix `A^∞`
+/// whose rows all equal the [consensus weights](stationary_weights) `w`.
+///
+/// The agents with a low persuadability but a high weight are the best targets of influence.
+///
+/// Returns [`None`] if the consensus weights cannot be computed.
+pub fn persuadability(a: &DMatrix<f64>) -> Option<DVector<f64>> {
+    stationary_weights(a).map(|weights| weights.map(|weight| 1. - weight))
+}
+
+/// Estimates the number of iterations of the [simulation](simulate) needed
+/// to reduce a unit spread of the opinions to `epsilon` without running it.
+///
+/// The spread decays as `|λ₂|ᵏ`, where `λ₂` is the second largest by modulus eigenvalue of `A`,
+/// so the estimate is `⌈ln ε / ln |λ₂|⌉`, and a small spectral gap `1 - |λ₂|`
+/// explains a slow convergence. For an initial spread `s` the simulation takes
+/// about `ln s / ln(1/|λ₂|)` iterations more.
+///
+/// Returns [`None`] if the matrix is not square or is not [primitive][1],
+/// in which case the consensus is not guaranteed.
+///
+/// [1]: https://en.wikipedia.org/wiki/Perron%E2%80%93Frobenius_theorem#Primitive_matrices
+pub fn mixing_time_estimate(a: &DMatrix<f64>, epsilon: f64) -> Option<usize> {
+    if a.is_empty() || !a.is_square() || !is_primitive(a) {
+        return None;
+    }
+
+    let mut moduli: Vec<_> = a
+        .complex_eigenvalues()
+        .iter()
+        .map(|eigenvalue| eigenvalue.re.hypot(eigenvalue.im))
+        .collect();
+    moduli.sort_by(|left, right| right.total_cmp(left));
+    let second = moduli.get(1).copied().unwrap_or(0.);
+    debug!("|λ₂| = {second}");
+
+    Some(if epsilon >= 1. {
+        0
+    } else if second <= f64::EPSILON {
+        1
+    } else {
+        (epsilon.ln() / second.ln()).ceil() as usize
+    })
+}
+
+/// Selects the `budget` agents with the largest influence on the consensus
+/// given the limiting influence matrix `A^∞`, e.g. the last of the [powers](influence_powers).
+///
+/// As the consensus is linear in the opinions, the influence of a group of agents
+/// is the sum of their weights, i.e. of the (averaged) columns of `A^∞`,
+/// so taking the heaviest agents is optimal.
+/// The agents are ordered from the most influential one.
+///
+/// # Panics
+///
+/// If the budget exceeds the number of agents.
+pub fn optimal_agents(a_final: &DMatrix<f64>, budget: usize) -> Vec<usize> {
+    assert!(
+        budget <= a_final.ncols(),
+        "the budget should not exceed the number of agents"
+    );
+
+    let weights = a_final.row_mean();
+    let mut agents: Vec<_> = (0..a_final.ncols()).collect();
+    agents.sort_by(|&left, &right| weights[right].total_cmp(&weights[left]));
+    agents.truncate(budget);
+    agents
+}
+
+/// Computes the powers `A, A², …, A^up_to` of the influence matrix,
+/// each by a single multiplication of the previous one.
+///
+/// The power `A^k` gives the influence of the initial opinions on the ones after `k` iterations,
+/// so for a primitive matrix its rows converge to the [consensus weights](stationary_weights).
+///
+/// # Panics
+///
+/// If the matrix is not square.
+pub fn influence_powers(a: &DMatrix<f64>, up_to: usize) -> Vec<DMatrix<f64>> {
+    assert!(a.is_square(), "the influence matrix should be square");
+
+    let mut powers: Vec<DMatrix<f64>> = Vec::with_capacity(up_to);
+    for _ in 0..up_to {
+        let power = powers
+            .last()
+            .map_or_else(|| a.clone(), |previous| previous * a);
+        powers.push(power);
+    }
+    powers
+}
+
+/// Finds the equilibrium magnitudes `(u*, v*)` of the influence of two players in closed form.
+///
+/// The consensus is `X = u·r_f + v·r_s`, where `r_f` and `r_s` are the total weights of the agents
+/// of the players, who maximize `Φ_f = aX - bX² - g_f·u²/2` and `Φ_s = cX - dX² - g_s·v²/2`.
+/// As both payoffs are concave, the equilibrium is given by the linear system
+/// `∂Φ_f/∂u = 0`, `∂Φ_s/∂v = 0`.
+///
+/// # Panics
+///
+/// If the system is singular, which is impossible for positive costs `g_f` and `g_s`.
+#[allow(clippy::too_many_arguments)]
+pub fn influence_equilibrium(
+    r_f: f64,
+    r_s: f64,
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    g_f: f64,
+    g_s: f64,
+) -> (f64, f64) {
+    let system = Matrix2::new(
+        2. * b * r_f * r_f + g_f,
+        2. * b * r_f * r_s,
+        2. * d * r_f * r_s,
+        2. * d * r_s * r_s + g_s,
+    );
+    let right = Vector2::new(a * r_f, c * r_s);
+    let solution = system
+        .lu()
+        .solve(&right)
+        .expect("the equilibrium system should not be singular");
+    (solution.x, solution.y)
+}
+
+/// Decides which of the players won the informational warfare
+/// by the distance of the reached consensus to their preferred opinions.
+pub fn warfare_outcome(consensus: f64, player1_target: f64, player2_target: f64) -> WarfareOutcome {
+    let distance_1 = (consensus - player1_target).abs();
+    let distance_2 = (consensus - player2_target).abs();
+    match distance_1.total_cmp(&distance_2) {
+        Ordering::Less => WarfareOutcome::FirstPlayerWins,
+        Ordering::Greater => WarfareOutcome::SecondPlayerWins,
+        Ordering::Equal => WarfareOutcome::Draw,
+    }
+}
+
+/// Checks if some power of the non-negative matrix is positive.
+///
+/// By Wielandt's theorem, it is enough to check the power `(n-1)² + 1`,
+/// and once a power is positive, all the higher ones are too.
+fn is_primitive(a: &DMatrix<f64>) -> bool {
+    let n = a.nrows();
+    let bound = (n - 1) * (n - 1) + 1;
+    let mut power = a.map(|value| usize::from(value > 0.));
+    let mut exponent = 1;
+    while exponent < bound {
+        power = (&power * &power).map(|value| value.min(1));
+        exponent *= 2;
+    }
+    power.iter().all(|&value| value > 0)
+}
+
+fn simulate_recording(
+    a: &DMatrix<f64>,
+    mut x: DVector<f64>,
+    epsilon: f64,
+    mut history: Option<Vec<DVector<f64>>>,
+) -> Consensus {
+    let mut iterations = 0;
+    loop {
+        if let Some(history) = &mut history {
+            history.push(x.clone());
+        }
+        if x.max() - x.min() <= epsilon {
+            break;
+        }
+
+        iterations += 1;
+        x = a * &x;
+        debug!("x({iterations}) = {}", x.transpose());
+    }
+
+    Consensus {
+        iterations,
+        opinions: x,
+        history,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+
+    #[test]
+    fn opinion_history() {
+        let a = dmatrix![
+            0.5, 0.5, 0.;
+            0.25, 0.5, 0.25;
+            0., 0.5, 0.5;
+        ];
+        let x = dvector![1., 5., 9.];
+
+        let consensus = simulate(&a, x.clone(), 1e-6);
+        assert_eq!(consensus.opinion_history(), None);
+
+        let recorded = simulate_with_history(&a, x.clone(), 1e-6);
+        let history = recorded.opinion_history().unwrap();
+        assert_eq!(history.len(), recorded.iterations + 1);
+        assert_eq!(history[0], x);
+        assert_eq!(history.last(), Some(&recorded.opinions));
+        assert_eq!(recorded.opinions, consensus.opinions);
+    }
+
+    #[test]
+    fn stationary_weights_match_simulation() {
+        let a = dmatrix![
+            0.5, 0.5, 0.;
+            0.25, 0.5, 0.25;
+            0., 0.5, 0.5;
+        ];
+        let x = dvector![1., 5., 9.];
+
+        let weights = stationary_weights(&a).unwrap();
+        assert!((weights.sum() - 1.).abs() < 1e-9);
+        assert!((weights.transpose() * &a - weights.transpose()).norm() < 1e-9);
+
+        let consensus = simulate(&a, x.clone(), 1e-9);
+        assert!((consensus.opinions.mean() - weights.dot(&x)).abs() < 1e-6);
+
+        // the agents of a periodic network never agree
+        assert_eq!(stationary_weights(&dmatrix![0., 1.; 1., 0.]), None);
+    }
+
+    #[test]
+    fn influence_powers_converge_to_consensus() {
+        let a = dmatrix![
+            0.5, 0.5, 0.;
+            0.25, 0.5, 0.25;
+            0., 0.5, 0.5;
+        ];
+        let powers = influence_powers(&a, 50);
+        assert_eq!(powers.len(), 50);
+        assert_eq!(powers[0], a);
+        assert_eq!(powers[2], &a * &a * &a);
+
+        // all the rows become the consensus weights, so the matrix is of rank one
+        let weights = stationary_weights(&a).unwrap().transpose();
+        let consensus = DMatrix::from_fn(3, 3, |_, column| weights[column]);
+        let last = powers.last().unwrap();
+        assert!((last - consensus).norm() < 1e-9);
+        assert_eq!(last.rank(1e-9), 1);
+
+        assert!(influence_powers(&a, 0).is_empty());
+    }
+
+    #[test]
+    fn stubborn_agent_is_least_persuadable() {
+        // the first agent mostly keeps its own opinion
+        let a = dmatrix![
+            0.9, 0.05, 0.05;
+            0.4, 0.3, 0.3;
+            0.4, 0.3, 0.3;
+        ];
+        let values = persuadability(&a).unwrap();
+        assert_eq!(values.argmin().0, 0);
+        assert!(values[0] < 0.5);
+        assert!(values[1] > 0.5 && values[2] > 0.5);
+        assert!((values.sum() - 2.).abs() < 1e-9);
+
+        assert_eq!(persuadability(&dmatrix![0., 1.; 1., 0.]), None);
+    }
+
+    #[test]
+    fn optimal_agents_are_most_influential() {
+        let a = dmatrix![
+            0.2, 0.3, 0.1, 0.4;
+            0.3, 0.3, 0.2, 0.2;
+            0.1, 0.5, 0.2, 0.2;
+            0.25, 0.25, 0.25, 0.25;
+        ];
+        let a_final = influence_powers(&a, 100).pop().unwrap();
+        let weights = stationary_weights(&a).unwrap();
+
+        let agents = optimal_agents(&a_final, 2);
+        assert_eq!(agents.len(), 2);
+        let weakest_selected = agents
+            .iter()
+            .map(|&agent| weights[agent])
+            .fold(f64::INFINITY, f64::min);
+        for agent in (0..4).filter(|agent| !agents.contains(agent)) {
+            assert!(weights[agent] <= weakest_selected);
+        }
+
+        assert_eq!(optimal_agents(&a_final, 0), Vec::<usize>::new());
+        assert_eq!(optimal_agents(&a_final, 4).len(), 4);
+    }
+
+    #[test]
+    fn influence_equilibrium_by_hand() {
+        // `4.5u + 1.5v = 2` and `u + 4v = 1`
+        let (u, v) = influence_equilibrium(0.5, 0.5, 4., 3., 2., 2., 3., 3.);
+        assert!((u - 13. / 33.).abs() < 1e-9);
+        assert!((v - 5. / 33.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sensitivity_sweep_over_agent_count() {
+        let a = dmatrix![
+            0.2, 0.3, 0.1, 0.4;
+            0.3, 0.3, 0.2, 0.2;
+            0.1, 0.5, 0.2, 0.2;
+            0.25, 0.25, 0.25, 0.25;
+        ];
+        let a_final = influence_powers(&a, 100).pop().unwrap();
+        let x = dvector![1., 2., 3., 4.];
+
+        // the player pushes the opinion `100` through more and more agents
+        let sweep = sensitivity_sweep(&a, &x, 1e-9, 0..=4, |&budget, x| {
+            for agent in optimal_agents(&a_final, budget) {
+                x[agent] = 100.;
+            }
+        });
+        assert_eq!(
+            sweep.iter().map(|&(budget, _)| budget).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+        assert!(sweep.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert!((sweep[4].1 - 100.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn warfare_outcome_by_distance() {
+        assert_eq!(warfare_outcome(2., 1., 5.), WarfareOutcome::FirstPlayerWins);
+        assert_eq!(
+            warfare_outcome(4., 1., 5.),
+            WarfareOutcome::SecondPlayerWins
+        );
+        assert_eq!(warfare_outcome(3., 1., 5.), WarfareOutcome::Draw);
+        assert_eq!(
+            warfare_outcome(-1., 1., -5.),
+            WarfareOutcome::FirstPlayerWins
+        );
+    }
+
+    #[test]
+    fn mixing_time_estimate_matches_simulation() {
+        let a = dmatrix![
+            0.5, 0.5, 0.;
+            0.25, 0.5, 0.25;
+            0., 0.5, 0.5;
+        ];
+        // the eigenvalues are `1`, `0.5` and `0`
+        let estimate = mixing_time_estimate(&a, 1e-9).unwrap();
+        assert_eq!(estimate, 30);
+
+        let observed = simulate(&a, dvector![1., 5., 9.], 1e-9).iterations;
+        assert!(estimate <= 2 * observed && observed <= 2 * estimate);
+
+        assert_eq!(mixing_time_estimate(&a, 1.), Some(0));
+        assert_eq!(mixing_time_estimate(&dmatrix![0., 1.; 1., 0.], 1e-9), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! Rendering of the games and their solutions as [`prettytable`] tables.
+
+use std::fmt::Display;
+
+use nalgebra::DVector;
+use prettytable::{format::consts::FORMAT_BOX_CHARS, Cell, Row, Table};
+
+use crate::{
+    cooperative::{CooperativeGame, ShapleyAnalysis},
+    non_cooperative::BiMatrixGame,
+    zero_sum::DGame,
+};
+
+/// A value which can be rendered as a table.
+pub trait ToTable {
+    fn to_table(&self) -> Table;
+}
+
+/// Creates the table with the given header formatted with the box characters.
+fn table_with_header(header: impl IntoIterator<Item = String>) -> Table {
+    let mut table = Table::new();
+    table.set_format(*FORMAT_BOX_CHARS);
+    table.set_titles(header.into_iter().map(|title| Cell::new(&title)).collect());
+    table
+}
+
+fn matrix_table(rows: usize, columns: usize, cell: impl Fn(usize, usize) -> String) -> Table {
+    let mut table = table_with_header(
+        std::iter::once(String::new()).chain((1..=columns).map(|column| format!("B{column}"))),
+    );
+    for row in 0..rows {
+        table.add_row(
+            std::iter::once(Cell::new(&format!("A{}", row + 1)))
+                .chain((0..columns).map(|column| Cell::new(&cell(row, column))))
+                .collect(),
+        );
+    }
+    table
+}
+
+/// The rows are labeled by the strategies of player A and the columns by the ones of player B.
+impl<T: Display> ToTable for DGame<T> {
+    fn to_table(&self) -> Table {
+        let Self(matrix) = self;
+        let (rows, columns) = matrix.shape();
+        matrix_table(rows, columns, |row, column| {
+            format!("{:.3}", matrix[(row, column)])
+        })
+    }
+}
+
+/// The rows are labeled by the strategies of player A and the columns by the ones of player B.
+impl<T: Display> ToTable for BiMatrixGame<T> {
+    fn to_table(&self) -> Table {
+        let Self(matrix) = self;
+        let (rows, columns) = matrix.shape();
+        matrix_table(rows, columns, |row, column| {
+            format!("{:.3}", matrix[(row, column)])
+        })
+    }
+}
+
+/// Lists the values of all the coalitions given by their bit masks.
+impl<T: Display> ToTable for CooperativeGame<T> {
+    fn to_table(&self) -> Table {
+        let players = self.player_count().get() as usize;
+        let mut table = table_with_header(["Coalition".to_owned(), "v".to_owned()]);
+        for coalition in self.coalitions() {
+            table.add_row(Row::new(vec![
+                Cell::new(&format!("{coalition:0players$}")),
+                Cell::new(&format!("{:.3}", self.v(coalition))),
+            ]));
+        }
+        table
+    }
+}
+
+impl CooperativeGame<f64> {
+    /// Lists the values and the sizes of all the coalitions
+    /// marking the ones involved in the [super-additivity](Self::super_additivity_violations)
+    /// or the [convexity](Self::convexity_violations) violations.
+    pub fn diagnostics_table(&self) -> Table {
+        let players = self.player_count().get() as usize;
+        let super_additivity = self.super_additivity_violations();
+        let convexity = self.convexity_violations();
+
+        let mut table = table_with_header([
+            "Coalition".to_owned(),
+            "Size".to_owned(),
+            "v".to_owned(),
+            "Super-additivity violated".to_owned(),
+            "Convexity violated".to_owned(),
+        ]);
+        for coalition in self.coalitions() {
+            let breaks_super_additivity = super_additivity
+                .iter()
+                .any(|&(s, t)| [s, t, s | t].contains(&coalition));
+            let breaks_convexity = convexity
+                .iter()
+                .any(|&(s, t)| [s, t, s | t, s & t].contains(&coalition));
+            table.add_row(Row::new(vec![
+                Cell::new(&format!("{coalition:0players$}")),
+                Cell::new(&coalition.n_members().to_string()),
+                Cell::new(&format!("{:.3}", self.v(coalition))),
+                Cell::new(&breaks_super_additivity.to_string()),
+                Cell::new(&breaks_convexity.to_string()),
+            ]));
+        }
+        table
+    }
+}
+
+/// Lists the shares of the players numbered from `1`.
+impl ToTable for ShapleyAnalysis {
+    fn to_table(&self) -> Table {
+        let mut table = table_with_header([
+            "Player".to_owned(),
+            "Shapley value".to_owned(),
+            "Individually rational".to_owned(),
+        ]);
+        for (player, (share, rational)) in self
+            .shapley_value
+            .iter()
+            .zip(&self.individually_rational)
+            .enumerate()
+        {
+            table.add_row(Row::new(vec![
+                Cell::new(&(player + 1).to_string()),
+                Cell::new(&format!("{share:.3}")),
+                Cell::new(&rational.to_string()),
+            ]));
+        }
+        table
+    }
+}
+
+/// Lists the probabilities of the strategies of both players followed by the value of the game.
+impl<T: Display> ToTable for (T, DVector<T>, DVector<T>) {
+    fn to_table(&self) -> Table {
+        let (value, x, y) = self;
+        let mut table = table_with_header([
+            "Player".to_owned(),
+            "Strategy".to_owned(),
+            "Probability".to_owned(),
+        ]);
+        for (player, prefix, strategy) in [("A", "x", x), ("B", "y", y)] {
+            for (index, probability) in strategy.iter().enumerate() {
+                table.add_row(Row::new(vec![
+                    Cell::new(player),
+                    Cell::new(&format!("{prefix}{}", index + 1)),
+                    Cell::new(&format!("{probability:.3}")),
+                ]));
+            }
+        }
+        table.add_row(Row::new(vec![
+            Cell::new(""),
+            Cell::new("Value"),
+            Cell::new(&format!("{value:.3}")),
+        ]));
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+    use crate::non_cooperative::Pair;
+
+    fn shape(table: &Table) -> (usize, usize) {
+        (table.len(), table.get_row(0).map_or(0, Row::len))
+    }
+
+    #[test]
+    fn zero_sum_game() {
+        let table = DGame::new(dmatrix![1, 2, 3; 4, 5, 6]).to_table();
+        assert_eq!(shape(&table), (2, 4));
+        assert!(table.to_string().contains("B3"));
+    }
+
+    #[test]
+    fn bi_matrix_game() {
+        let table = BiMatrixGame::new(dmatrix![Pair(1., 2.); Pair(3., 4.5)]).to_table();
+        assert_eq!(shape(&table), (2, 2));
+        assert_eq!(table[1][1].get_content(), "(3.000, 4.500)");
+    }
+
+    #[test]
+    fn cooperative_game() {
+        let game = CooperativeGame::new(vec![0., 1., 1., 3.]).unwrap();
+        let table = game.to_table();
+        assert_eq!(shape(&table), (4, 2));
+        assert!(table.to_string().contains("01"));
+        assert_eq!(shape(&game.analyze_shapley().to_table()), (2, 3));
+    }
+
+    #[test]
+    fn cooperative_diagnostics() {
+        // the players get more separately than together
+        let game = CooperativeGame::new(vec![0., 1., 1., 1.]).unwrap();
+        assert_eq!(game.super_additivity_violations().len(), 1);
+
+        let table = game.diagnostics_table();
+        assert_eq!(shape(&table), (4, 5));
+        let flag = |row: usize, column: usize| table[row][column].get_content();
+        assert_eq!(flag(0, 3), "false");
+        for row in 1..4 {
+            assert_eq!(flag(row, 3), "true");
+            assert_eq!(flag(row, 4), "true");
+        }
+    }
+
+    #[test]
+    fn mixed_solution() {
+        let solution = (0.2, dvector![0.4, 0.6], dvector![0.4, 0.6]);
+        assert_eq!(shape(&solution.to_table()), (5, 3));
+    }
+}
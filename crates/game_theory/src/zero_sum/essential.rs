@@ -0,0 +1,96 @@
+use nalgebra::{DMatrix, DVector};
+
+use super::DGame;
+use crate::simplex;
+
+/// Tolerance used to relax the optimality constraints against the rounding errors of the value.
+const RELAXATION: f64 = 1e-9;
+
+/// Tolerance used to compare the probabilities with zero,
+/// which should be well above the ones allowed by the [relaxation](RELAXATION).
+const EPSILON: f64 = 1e-6;
+
+impl DGame<f64> {
+    /// Finds the essential strategies of both players,
+    /// i.e. the union of the supports of all their optimal strategies.
+    ///
+    /// The strategies outside of these sets are not used by any optimal strategy,
+    /// so they can always be removed from the game.
+    /// Instead of enumerating the vertices of the polytope of the optimal strategies,
+    /// a strategy is checked to be essential by maximizing its probability over the polytope
+    /// via a [linear program](simplex), so a program is solved per strategy
+    /// which is only suitable for small games.
+    ///
+    /// Returns empty sets if the game is empty.
+    #[must_use]
+    pub fn essential_strategies(&self) -> (Vec<usize>, Vec<usize>) {
+        let Some((value, _, _)) = self.solve_linear_programming() else {
+            return (Vec::new(), Vec::new());
+        };
+        let Self(matrix) = self;
+
+        // the columns are the rows of the complementary game
+        (
+            essential_rows(matrix, value),
+            essential_rows(&self.complementary().0, -value),
+        )
+    }
+}
+
+/// Finds the rows used by any strategy of player A guaranteeing the `value` of the game.
+fn essential_rows(matrix: &DMatrix<f64>, value: f64) -> Vec<usize> {
+    let (rows, columns) = matrix.shape();
+
+    // `xᵀM >= value` for each column and `Σx = 1`
+    let mut a = DMatrix::zeros(columns + 2, rows);
+    a.rows_mut(0, columns).copy_from(&-matrix.transpose());
+    a.row_mut(columns).fill(1.);
+    a.row_mut(columns + 1).fill(-1.);
+    let mut b = DVector::repeat(columns + 2, RELAXATION - value);
+    b[columns] = 1.;
+    b[columns + 1] = -1.;
+
+    (0..rows)
+        .filter(|&row| {
+            let mut c = DVector::zeros(rows);
+            c[row] = 1.;
+            simplex::maximize(&c, &a, &b).is_ok_and(|solution| solution.value > EPSILON)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn degenerate_game() {
+        // any mix of the first two strategies is optimal for both players
+        let game = DGame::new(dmatrix![
+            1., 1., 2.;
+            1., 1., 2.;
+            0., 0., 3.;
+        ]);
+        assert_eq!(game.essential_strategies(), (vec![0, 1], vec![0, 1]));
+    }
+
+    #[test]
+    fn unique_solution() {
+        let game = DGame::new(dmatrix![
+            2., -1., 3.;
+            -1., 1., 3.;
+            -2., 0., 4.;
+        ]);
+        let (_, x, y) = game.solve_linear_programming().unwrap();
+        assert!(x[2].abs() < 1e-9 && y[2].abs() < 1e-9);
+        assert_eq!(game.essential_strategies(), (vec![0, 1], vec![0, 1]));
+    }
+
+    #[test]
+    fn empty_game() {
+        let game = DGame::<f64>::new(dmatrix![]);
+        assert_eq!(game.essential_strategies(), (vec![], vec![]));
+    }
+}
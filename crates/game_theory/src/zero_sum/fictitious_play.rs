@@ -0,0 +1,106 @@
+use std::num::NonZeroUsize;
+
+use nalgebra::{DVector, RealField};
+
+use super::DGame;
+
+/// Solves the game approximately by [fictitious play][1].
+///
+/// On each iteration both players choose the best response
+/// to the accumulated history of their opponent's choices.
+/// The players start with their first strategies and ties are resolved towards lower indices
+/// so that the result is deterministic.
+///
+/// Returns the estimation of the game's value
+/// as well as the time-averaged strategies of players A and B.
+///
+/// The convergence is guaranteed by Robinson's theorem, yet it is slow:
+/// the gap between the upper and the lower bounds of the value
+/// decreases as `O(k^(-1 / (m + n - 2)))` for an `m×n` game after `k` iterations in the worst case,
+/// although it is usually closer to `O(k^(-1/2))` in practice.
+///
+/// [1]: https://en.wikipedia.org/wiki/Fictitious_play
+///
+/// # Panics
+///
+/// If the game is empty.
+#[must_use]
+pub fn fictitious_play<T: RealField + Copy>(
+    game: &DGame<T>,
+    iterations: NonZeroUsize,
+) -> (T, DVector<T>, DVector<T>) {
+    let matrix = &game.0;
+    assert!(!matrix.is_empty(), "the game should not be empty");
+    let (rows, columns) = matrix.shape();
+
+    let mut a_scores = DVector::<T>::zeros(rows);
+    let mut b_scores = DVector::<T>::zeros(columns);
+    let mut a_times_used = DVector::<T>::zeros(rows);
+    let mut b_times_used = DVector::<T>::zeros(columns);
+
+    let (mut a_strategy, mut b_strategy) = (0, 0);
+    let mut min_high_price = T::max_value().expect("real fields should be bounded");
+    let mut max_low_price = T::min_value().expect("real fields should be bounded");
+    let mut k = T::zero();
+    for _ in 0..iterations.get() {
+        k += T::one();
+        a_times_used[a_strategy] += T::one();
+        b_times_used[b_strategy] += T::one();
+        a_scores += matrix.column(b_strategy);
+        b_scores += matrix.row(a_strategy).transpose();
+
+        let (next_a_strategy, high_score) = first_extremum(&a_scores, |new, old| new > old);
+        let (next_b_strategy, low_score) = first_extremum(&b_scores, |new, old| new < old);
+        min_high_price = min_high_price.min(high_score / k);
+        max_low_price = max_low_price.max(low_score / k);
+
+        (a_strategy, b_strategy) = (next_a_strategy, next_b_strategy);
+    }
+
+    (
+        (min_high_price + max_low_price) / (T::one() + T::one()),
+        a_times_used / k,
+        b_times_used / k,
+    )
+}
+
+fn first_extremum<T: RealField + Copy>(
+    values: &DVector<T>,
+    is_better: impl Fn(T, T) -> bool,
+) -> (usize, T) {
+    values
+        .iter()
+        .copied()
+        .enumerate()
+        .reduce(|best, candidate| {
+            if is_better(candidate.1, best.1) {
+                candidate
+            } else {
+                best
+            }
+        })
+        .expect("the values should not be empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn converges_to_analytic_solution() {
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let (x, y) = game.solve_analytically().unwrap();
+
+        let (value, a_strategy, b_strategy) =
+            fictitious_play(&game, NonZeroUsize::new(10_000).unwrap());
+        assert!((value - y[2]).abs() < 1e-2, "{value} != {}", y[2]);
+        assert!((a_strategy[0] - x[0]).abs() < 1e-2, "{a_strategy}");
+        assert!((b_strategy[0] - y[0]).abs() < 1e-2, "{b_strategy}");
+        assert!((a_strategy.sum() - 1.).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,100 @@
+use nalgebra::{DVector, RealField};
+
+use super::DGame;
+
+/// Payoff of a pure strategy against the mixed strategy of the opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrategyPayoff<T> {
+    pub payoff: T,
+    /// Whether the strategy is used with a non-zero probability.
+    pub in_support: bool,
+    /// Whether the payoff equals the value of the game.
+    pub is_indifferent: bool,
+}
+
+/// Diagnostics of the [indifference principle](DGame::verify_indifference)
+/// for the given mixed strategies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndifferenceReport<T> {
+    /// The expected payoff of the strategy profile.
+    pub value: T,
+    /// The payoffs of the pure strategies of player A against the strategy of player B.
+    pub rows: Vec<StrategyPayoff<T>>,
+    /// The payoffs of the pure strategies of player B against the strategy of player A.
+    pub columns: Vec<StrategyPayoff<T>>,
+}
+
+impl<T> IndifferenceReport<T> {
+    /// Checks if all the strategies in the supports yield the value of the game.
+    pub fn holds(&self) -> bool {
+        self.rows
+            .iter()
+            .chain(&self.columns)
+            .all(|strategy| !strategy.in_support || strategy.is_indifferent)
+    }
+}
+
+impl<T: RealField + Copy> DGame<T> {
+    /// Verifies the indifference principle for the mixed strategies `x` and `y`:
+    /// each pure strategy used in an equilibrium yields the value of the game
+    /// against the opponent's equilibrium strategy.
+    ///
+    /// The payoffs and the probabilities are compared up to `tolerance`.
+    #[must_use]
+    pub fn verify_indifference(
+        &self,
+        x: &DVector<T>,
+        y: &DVector<T>,
+        tolerance: T,
+    ) -> IndifferenceReport<T> {
+        let matrix = &self.0;
+        let row_payoffs = matrix * y;
+        let column_payoffs = matrix.tr_mul(x);
+        let value = x.dot(&row_payoffs);
+
+        let report = |payoffs: DVector<T>, strategy: &DVector<T>| {
+            payoffs
+                .iter()
+                .zip(strategy.iter())
+                .map(|(&payoff, &probability)| StrategyPayoff {
+                    payoff,
+                    in_support: probability > tolerance,
+                    is_indifferent: (payoff - value).abs() <= tolerance,
+                })
+                .collect()
+        };
+        IndifferenceReport {
+            value,
+            rows: report(row_payoffs, x),
+            columns: report(column_payoffs, y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+
+    #[test]
+    fn verify_indifference() {
+        let game = DGame::new(dmatrix![
+            2f64, -1., 3.;
+            -1., 1., 2.;
+        ]);
+        let report = game.verify_indifference(&dvector![0.4, 0.6], &dvector![0.4, 0.6, 0.], 1e-9);
+
+        assert!((report.value - 0.2).abs() < 1e-9);
+        assert!(report.holds());
+        assert!(report
+            .rows
+            .iter()
+            .all(|row| row.in_support && row.is_indifferent));
+        assert!(report.columns[0].is_indifferent && report.columns[1].is_indifferent);
+        assert!(!report.columns[2].in_support && !report.columns[2].is_indifferent);
+
+        let report = game.verify_indifference(&dvector![1., 0.], &dvector![0.4, 0.6, 0.], 1e-9);
+        assert!(!report.holds());
+    }
+}
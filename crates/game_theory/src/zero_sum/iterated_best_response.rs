@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use super::DGame;
+
+/// The outcome of the [iterated best response](DGame::iterated_best_response).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IteratedBROutcome {
+    /// The last visited profile is a saddle point.
+    Converged,
+    /// The visited profiles starting from the given index repeat forever.
+    Cycle { start: usize },
+    /// The step limit was reached before any of the above happened.
+    StepLimit,
+}
+
+/// The strategy profiles visited by the [iterated best response](DGame::iterated_best_response).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IteratedBRResult {
+    pub outcome: IteratedBROutcome,
+    /// The visited profiles as `(row, column)`, starting from the initial one.
+    pub visited: Vec<(usize, usize)>,
+}
+
+impl IteratedBRResult {
+    /// Gets the profiles forming the cycle, if the dynamics cycles.
+    pub fn cycle(&self) -> Option<&[(usize, usize)]> {
+        match self.outcome {
+            IteratedBROutcome::Cycle { start } => Some(&self.visited[start..]),
+            _ => None,
+        }
+    }
+}
+
+impl<T: PartialOrd> DGame<T> {
+    /// Runs the pure best-response dynamics for at most `max_steps` steps
+    /// starting from the profile `(start_row, start_column)`.
+    ///
+    /// On each step player A switches to the row maximizing its win against the current column,
+    /// then player B switches to the column minimizing its loss against the new row.
+    /// The players stick to their current strategies while those remain best responses,
+    /// otherwise they pick the first of the best responses.
+    ///
+    /// The dynamics only converges if the game has a saddle point;
+    /// otherwise it cycles, which is why [fictitious play](super::fictitious_play)
+    /// responds to the accumulated history of the opponent instead of its last choice.
+    ///
+    /// # Panics
+    ///
+    /// If the start profile exceeds the dimensions of the game.
+    #[must_use]
+    pub fn iterated_best_response(
+        &self,
+        start_row: usize,
+        start_column: usize,
+        max_steps: usize,
+    ) -> IteratedBRResult {
+        let Self(matrix) = self;
+        let (rows, columns) = matrix.shape();
+        assert!(
+            start_row < rows && start_column < columns,
+            "({start_row}, {start_column}) exceeds the game of {rows}×{columns}"
+        );
+
+        let mut profile = (start_row, start_column);
+        let mut visited = vec![profile];
+        let mut indices = HashMap::from([(profile, 0)]);
+        for _ in 0..max_steps {
+            let row = best_response(
+                rows,
+                profile.0,
+                |row| &matrix[(row, profile.1)],
+                |new, old| new > old,
+            );
+            let column = best_response(
+                columns,
+                profile.1,
+                |column| &matrix[(row, column)],
+                |new, old| new < old,
+            );
+
+            if (row, column) == profile {
+                return IteratedBRResult {
+                    outcome: IteratedBROutcome::Converged,
+                    visited,
+                };
+            }
+            profile = (row, column);
+            if let Some(&start) = indices.get(&profile) {
+                return IteratedBRResult {
+                    outcome: IteratedBROutcome::Cycle { start },
+                    visited,
+                };
+            }
+            indices.insert(profile, visited.len());
+            visited.push(profile);
+        }
+
+        IteratedBRResult {
+            outcome: IteratedBROutcome::StepLimit,
+            visited,
+        }
+    }
+}
+
+/// Finds the best of the `count` strategies by their `value`s,
+/// preferring to keep the `current` strategy.
+fn best_response<'a, T: PartialOrd + 'a>(
+    count: usize,
+    current: usize,
+    value: impl Fn(usize) -> &'a T,
+    is_better: impl Fn(&T, &T) -> bool,
+) -> usize {
+    let best = (0..count)
+        .reduce(|best, strategy| {
+            if is_better(value(strategy), value(best)) {
+                strategy
+            } else {
+                best
+            }
+        })
+        .expect("the game should not be empty");
+    if is_better(value(best), value(current)) {
+        best
+    } else {
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn converges_to_saddle_point() {
+        let game = DGame::new(dmatrix![
+            3, 1, 2;
+            4, 0, -1;
+            5, 2, 3;
+        ]);
+        let result = game.iterated_best_response(1, 0, 10);
+
+        assert_eq!(result.outcome, IteratedBROutcome::Converged);
+        assert_eq!(result.visited, vec![(1, 0), (2, 1)]);
+        assert_eq!(game.saddle_points(), vec![(2, 1)]);
+    }
+
+    #[test]
+    fn matching_pennies_cycles() {
+        let game = DGame::new(dmatrix![
+            1, -1;
+            -1, 1;
+        ]);
+        let result = game.iterated_best_response(0, 0, 10);
+
+        assert_eq!(result.outcome, IteratedBROutcome::Cycle { start: 1 });
+        assert_eq!(result.visited, vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(result.cycle(), Some(&[(0, 1), (1, 0)][..]));
+
+        let result = game.iterated_best_response(0, 0, 1);
+        assert_eq!(result.outcome, IteratedBROutcome::StepLimit);
+        assert_eq!(result.cycle(), None);
+    }
+}
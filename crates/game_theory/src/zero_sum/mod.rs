@@ -1,17 +1,39 @@
 //! [Zero-sum (antagonistic) games][1].
 //!
+//! All the solvers assume that the matrix holds the wins of player A (rows),
+//! i.e. A maximizes the payoff while B (columns) minimizes it.
+//! Games given by the costs of player A should be converted via [`Game::as_cost_game`] first.
+//!
 //! [1]: https://en.wikipedia.org/wiki/Zero-sum_game
 
-use std::{fmt, fmt::Formatter};
+use std::{fmt, fmt::Formatter, ops::Neg};
 
+pub use fictitious_play::fictitious_play;
+pub use indifference::{IndifferenceReport, StrategyPayoff};
+pub use iterated_best_response::{IteratedBROutcome, IteratedBRResult};
 use nalgebra::{
     allocator::{Allocator, Reallocator},
-    ComplexField, DMatrix, DVector, DefaultAllocator, Dim, DimAdd, DimMin, DimMinimum, DimSum, Dyn,
-    Matrix, OMatrix, RawStorageMut, SimdPartialOrd, Storage, VecStorage, U1,
+    ClosedAdd, ComplexField, DMatrix, DVector, DefaultAllocator, Dim, DimAdd, DimMin, DimMinimum,
+    DimSum, Dyn, Matrix, OMatrix, RawStorageMut, RealField, Scalar, SimdPartialOrd, Storage,
+    VecStorage, U1,
 };
 pub use parse::FromStrError as GameFromStrError;
+pub use reduction::ReducedGame;
+pub use solver::{Analytic, FictitiousPlay, LinearProgramming, MixedSolution, ZeroSumSolver};
+pub use validate::GameValidationIssue;
+pub use worked_solution::SolutionStep;
 
+mod essential;
+mod fictitious_play;
+mod indifference;
+mod iterated_best_response;
 mod parse;
+mod reduction;
+mod simulation;
+mod solver;
+mod tiebreak;
+mod validate;
+mod worked_solution;
 
 /// A zeros-sum game defined by its matrix.
 #[non_exhaustive]
@@ -33,6 +55,72 @@ impl<M> Game<M> {
     }
 }
 
+impl<T: Scalar + Neg<Output = T>> DGame<T> {
+    /// Converts the game in which player A minimizes the costs
+    /// into the equivalent game in which A maximizes the wins, by negating the matrix.
+    ///
+    /// The values of the converted game are the negated costs of the original one
+    /// while the optimal strategies stay the same.
+    #[must_use]
+    pub fn as_cost_game(&self) -> Self {
+        Self(self.0.map(|value| -value))
+    }
+
+    /// Creates the game seen from the side of player B, i.e. with the matrix `-Mᵀ`.
+    ///
+    /// The players swap their roles: the optimal row strategies of the complementary game
+    /// are the optimal column strategies of the original one and vice versa,
+    /// while its value is the negated value of the original game.
+    #[must_use]
+    pub fn complementary(&self) -> Self {
+        Self(self.0.transpose().map(|value| -value))
+    }
+}
+
+impl<T: Scalar + PartialOrd> DGame<T> {
+    /// Finds all the saddle points of the game as `(row, column)`,
+    /// i.e. the cells which are the minimum of their row and the maximum of their column.
+    ///
+    /// If there are any, the game is solved in pure strategies
+    /// and all the saddle points have the same value.
+    #[must_use]
+    pub fn saddle_points(&self) -> Vec<(usize, usize)> {
+        let Self(matrix) = self;
+        let mut points = Vec::new();
+        for row in 0..matrix.nrows() {
+            for column in 0..matrix.ncols() {
+                let value = &matrix[(row, column)];
+                if matrix.row(row).iter().all(|other| other >= value)
+                    && matrix.column(column).iter().all(|other| other <= value)
+                {
+                    points.push((row, column));
+                }
+            }
+        }
+        points
+    }
+}
+
+impl<T: Scalar + Copy + PartialOrd + ClosedAdd> DGame<T> {
+    /// Computes the outcome of a `2×2` game in which each player chooses the risk-dominant strategy,
+    /// i.e. the best response to the opponent mixing its strategies uniformly.
+    ///
+    /// Ties are resolved towards the first strategies.
+    /// Returns [`None`] if the game is not `2×2`.
+    #[must_use]
+    pub fn risk_dominant_value(&self) -> Option<T> {
+        let Self(matrix) = self;
+        if matrix.shape() != (2, 2) {
+            return None;
+        }
+
+        // the uniform mixing only scales the sums of the payoffs
+        let row = usize::from(matrix[(1, 0)] + matrix[(1, 1)] > matrix[(0, 0)] + matrix[(0, 1)]);
+        let column = usize::from(matrix[(0, 1)] + matrix[(1, 1)] < matrix[(0, 0)] + matrix[(1, 0)]);
+        Some(matrix[(row, column)])
+    }
+}
+
 #[allow(type_alias_bounds)] // just for clarity
 pub type Strategy<T, N: DimAdd<U1>> = OMatrix<T, DimPlus1<N>, U1>;
 
@@ -60,6 +148,23 @@ impl<T: ComplexField, N: Dim, S: Storage<T, N, N>> Game<Matrix<T, N, N, S>> {
         }
     }
 
+    /// Finds only the optimal strategy of player A,
+    /// solving a half of the system [solved analytically](Self::solve_analytically).
+    #[must_use]
+    pub fn optimal_row_strategy(&self) -> Option<DVector<T>>
+    where
+        N: DimAdd<U1>,
+        DimPlus1<N>: DimMin<DimPlus1<N>, Output = DimPlus1<N>>,
+        DefaultAllocator: Allocator<T, DimPlus1<N>>
+            + Reallocator<T, N, N, DimPlus1<N>, N>
+            + Reallocator<T, DimPlus1<N>, N, DimPlus1<N>, DimPlus1<N>>,
+    {
+        let solution = self.0.transpose().solve_game()?;
+        // the last value is the value of the game
+        let n = self.0.nrows();
+        Some(DVector::from_iterator(n, solution.iter().take(n).cloned()))
+    }
+
     // TODO: maybe, use a more economical return-type of the matrix ones,
     //  since the dimensions are well-defined
 
@@ -98,6 +203,70 @@ impl<T: ComplexField, N: Dim, S: Storage<T, N, N>> Game<Matrix<T, N, N, S>> {
     }
 }
 
+impl<T: RealField + Copy> DGame<T> {
+    /// Computes the best value player A can achieve against the mixed strategy `y` of player B.
+    #[must_use]
+    pub fn best_response_value_a(&self, y: &DVector<T>) -> T {
+        (&self.0 * y).max()
+    }
+
+    /// Computes the least value player B can concede against the mixed strategy `x` of player A.
+    #[must_use]
+    pub fn best_response_value_b(&self, x: &DVector<T>) -> T {
+        (x.transpose() * &self.0).min()
+    }
+
+    /// Computes the exploitability of the strategy profile `(x, y)`,
+    /// i.e. the sum of the gains both players get by switching to their best responses.
+    ///
+    /// The exploitability is non-negative and equals zero only on equilibria.
+    #[must_use]
+    pub fn exploitability(&self, x: &DVector<T>, y: &DVector<T>) -> T {
+        // the value of the profile itself cancels out
+        self.best_response_value_a(y) - self.best_response_value_b(x)
+    }
+
+    /// Checks if the `other` game is a positive affine transformation of this one,
+    /// i.e. `M' = kM + c` for some `k > 0`, up to the `tolerance`.
+    ///
+    /// Such games have the same optimal strategies while their values are transformed likewise.
+    #[must_use]
+    pub fn is_strategically_equivalent(&self, other: &Self, tolerance: T) -> bool {
+        let (Self(matrix), Self(other)) = (self, other);
+        if matrix.shape() != other.shape() || matrix.is_empty() {
+            return matrix.shape() == other.shape();
+        }
+
+        // the extreme values give the most precise estimation of the transformation
+        let extreme = |is_better: fn(&T, &T) -> bool| {
+            (0..matrix.len())
+                .reduce(|best, index| {
+                    if is_better(&matrix[index], &matrix[best]) {
+                        index
+                    } else {
+                        best
+                    }
+                })
+                .expect("the game is not empty")
+        };
+        let (min, max) = (extreme(|new, old| new < old), extreme(|new, old| new > old));
+        let spread = matrix[max] - matrix[min];
+        let (k, c) = if spread > tolerance {
+            let k = (other[max] - other[min]) / spread;
+            (k, other[min] - k * matrix[min])
+        } else {
+            // any positive `k` fits a constant game
+            (T::one(), other[min] - matrix[min])
+        };
+
+        k > T::zero()
+            && matrix
+                .iter()
+                .zip(other.iter())
+                .all(|(&value, &other)| (k * value + c - other).abs() <= tolerance)
+    }
+}
+
 #[allow(type_alias_bounds)] // just for clarity
 type DimPlus1<D: DimAdd<U1>> = DimSum<D, U1>;
 
@@ -162,3 +331,119 @@ where
 
     a.solve_mut(&mut b).then_some(b)
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+
+    #[test]
+    fn exploitability() {
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let (x, y) = game.solve_analytically().unwrap();
+        let (x, y) = (x.rows(0, 2).into_owned(), y.rows(0, 2).into_owned());
+        assert!(game.exploitability(&x, &y).abs() < 1e-9);
+
+        let pure = dvector![1., 0.];
+        assert!((game.exploitability(&pure, &pure) - 3.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn saddle_points() {
+        let game = DGame::new(dmatrix![
+            1, 2, 1;
+            0, 5, -1;
+            1, 3, 1;
+        ]);
+        assert_eq!(game.saddle_points(), [(0, 0), (0, 2), (2, 0), (2, 2)]);
+        assert!(DGame::new(dmatrix![1, -1; -1, 1])
+            .saddle_points()
+            .is_empty());
+    }
+
+    #[test]
+    fn risk_dominant_value() {
+        // A hopes for the first cell, while B avoids the column in which it risks losing `3`
+        let game = DGame::new(dmatrix![
+            3, 0;
+            0, 1;
+        ]);
+        assert_eq!(game.risk_dominant_value(), Some(0));
+        assert_eq!(game.as_cost_game().risk_dominant_value(), Some(0));
+        assert_eq!(DGame::new(dmatrix![1, 2, 3]).risk_dominant_value(), None);
+    }
+
+    #[test]
+    fn complementary_game() {
+        let game = DGame::new(dmatrix![
+            2., -1., 3.;
+            -1., 1., 2.;
+        ]);
+        let complementary = game.complementary();
+        assert_eq!(
+            complementary,
+            DGame::new(dmatrix![
+                -2., 1.;
+                1., -1.;
+                -3., -2.;
+            ])
+        );
+
+        let (value, x, y) = game.solve_linear_programming().unwrap();
+        let (complementary_value, complementary_x, complementary_y) =
+            complementary.solve_linear_programming().unwrap();
+        assert!((complementary_value + value).abs() < 1e-9);
+        assert!((complementary_x - y).norm() < 1e-9);
+        assert!((complementary_y - x).norm() < 1e-9);
+        assert_eq!(complementary.complementary(), game);
+    }
+
+    #[test]
+    fn optimal_row_strategy() {
+        let game = DGame::new(dmatrix![
+            2f64, -1., 0.;
+            -1., 1., 3.;
+            0., 2., -2.;
+        ]);
+        let (x, _) = game.solve_analytically().unwrap();
+        let row_strategy = game.optimal_row_strategy().unwrap();
+        assert!((row_strategy - x.rows(0, 3)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn strategic_equivalence() {
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let transformed = DGame::new(game.0.map(|value| 3. * value + 2.));
+        assert!(game.is_strategically_equivalent(&transformed, 1e-9));
+        assert!(transformed.is_strategically_equivalent(&game, 1e-9));
+
+        assert!(!game.is_strategically_equivalent(&game.as_cost_game(), 1e-9));
+        let perturbed = DGame::new(dmatrix![
+            8., -1.;
+            -1., 6.;
+        ]);
+        assert!(!game.is_strategically_equivalent(&perturbed, 1e-9));
+        assert!(!game.is_strategically_equivalent(&DGame::new(dmatrix![2., -1.]), 1e-9));
+    }
+
+    #[test]
+    fn cost_game() {
+        // player A minimizes the costs: `max(2 + 2p, 3 - 2p)` is minimal at `p = 1/4`,
+        // while B maximizes them: `min(1 + 3q, 3 - q)` is maximal at `q = 1/2`
+        let costs = DGame::new(dmatrix![
+            4f64, 1.;
+            2., 3.;
+        ]);
+        let (value, x, y) = costs.as_cost_game().solve_linear_programming().unwrap();
+        assert!((value + 2.5).abs() < 1e-9);
+        assert!((x - dvector![0.25, 0.75]).norm() < 1e-9);
+        assert!((y - dvector![0.5, 0.5]).norm() < 1e-9);
+    }
+}
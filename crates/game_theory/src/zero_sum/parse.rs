@@ -14,6 +14,20 @@ impl FromStr for DGame<f64> {
     }
 }
 
+impl DGame<f64> {
+    /// Parses the game rejecting the matrices exceeding the given dimensions.
+    ///
+    /// Unlike [`FromStr`], this stops reading the values beyond the bounds,
+    /// so it is safe to use on the untrusted input.
+    pub fn from_str_bounded(
+        s: &str,
+        max_rows: usize,
+        max_columns: usize,
+    ) -> Result<Self, FromStrError> {
+        Ok(game::bounded_dgame(s, max_rows, max_columns)?)
+    }
+}
+
 impl FromStr for BiMatrixGame<f64> {
     type Err = FromStrError;
 
@@ -28,8 +42,10 @@ pub struct FromStrError(#[from] ParseError<LineCol>);
 
 peg::parser! {
     grammar game() for str {
-        pub rule dgame<T: FromStr>() -> DGame<T>
-            = "{" rows:((_ v:row() _ { v }) ** ";") _ ";"? _ "}"
+        pub rule dgame<T: FromStr>() -> DGame<T> = bounded_dgame(usize::MAX, usize::MAX)
+
+        pub rule bounded_dgame<T: FromStr>(max_rows: usize, max_columns: usize) -> DGame<T>
+            = "{" rows:((_ v:row(max_columns) _ { v }) **<,{max_rows}> ";") _ ";"? _ "}"
         {?
             Ok(Game(dmatrix_from_rows(rows)?))
         }
@@ -42,8 +58,8 @@ peg::parser! {
 
         rule _() = [' ' | '\t' | '\r' | '\n']*
 
-        rule row<T: FromStr>() -> Vec<T>
-            = "[" values:((_ v:float() _ { v }) ** ",") _ ","? _ "]"
+        rule row<T: FromStr>(max_columns: usize) -> Vec<T>
+            = "[" values:((_ v:float() _ { v }) **<,{max_columns}> ",") _ ","? _ "]"
         {
             values
         }
@@ -153,4 +169,17 @@ mod tests {
             ])),
         );
     }
+
+    #[test]
+    fn bounded_matrix() {
+        assert_eq!(
+            DGame::from_str_bounded("{[1, 2]; [3, 4]}", 2, 2).unwrap(),
+            Game(dmatrix![
+                    1., 2.;
+                    3., 4.;
+            ]),
+        );
+        assert!(DGame::from_str_bounded("{[1, 2]; [3, 4]; [5, 6]}", 2, 2).is_err());
+        assert!(DGame::from_str_bounded("{[1, 2, 3]; [4, 5, 6]}", 2, 2).is_err());
+    }
 }
@@ -0,0 +1,124 @@
+use nalgebra::Scalar;
+
+use super::DGame;
+
+/// The [reduced form](DGame::reduced_form) of a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducedGame<T> {
+    /// The game consisting of the remaining strategies.
+    pub game: DGame<T>,
+    /// The original indices of the remaining strategies of player A.
+    pub rows: Vec<usize>,
+    /// The original indices of the remaining strategies of player B.
+    pub columns: Vec<usize>,
+    /// The value of the game if it was reduced to a single cell, i.e. a saddle point.
+    pub saddle_value: Option<T>,
+}
+
+impl<T: Scalar + PartialOrd> DGame<T> {
+    /// Reduces the game to the smallest equivalent one.
+    ///
+    /// If the game has a [saddle point](Self::saddle_points), it is the whole solution.
+    /// Otherwise, the weakly dominated strategies are eliminated one by one
+    /// until none remain: the rows which never win more than another row
+    /// and the columns which never lose less than another column.
+    /// Although the elimination of weakly dominated strategies may lose some of the equilibria,
+    /// the value of the game is preserved
+    /// and any solution of the reduced game is a solution of the original one.
+    #[must_use]
+    pub fn reduced_form(&self) -> ReducedGame<T> {
+        let Self(matrix) = self;
+        if let Some(&(row, column)) = self.saddle_points().first() {
+            return ReducedGame {
+                game: Self(matrix.select_rows(&[row]).select_columns(&[column])),
+                rows: vec![row],
+                columns: vec![column],
+                saddle_value: Some(matrix[(row, column)].clone()),
+            };
+        }
+
+        let mut rows: Vec<_> = (0..matrix.nrows()).collect();
+        let mut columns: Vec<_> = (0..matrix.ncols()).collect();
+        loop {
+            let dominated_row = find_dominated(&rows, |row, other| {
+                columns
+                    .iter()
+                    .all(|&column| matrix[(row, column)] <= matrix[(other, column)])
+            });
+            if let Some(index) = dominated_row {
+                rows.remove(index);
+                continue;
+            }
+
+            let dominated_column = find_dominated(&columns, |column, other| {
+                rows.iter()
+                    .all(|&row| matrix[(row, column)] >= matrix[(row, other)])
+            });
+            if let Some(index) = dominated_column {
+                columns.remove(index);
+                continue;
+            }
+
+            break;
+        }
+
+        let game = Self(matrix.select_rows(&rows).select_columns(&columns));
+        let saddle_value = (game.0.len() == 1).then(|| game.0[(0, 0)].clone());
+        ReducedGame {
+            game,
+            rows,
+            columns,
+            saddle_value,
+        }
+    }
+}
+
+/// Finds the index of a strategy which is dominated by another one
+/// according to `is_dominated(strategy, other)`.
+fn find_dominated(
+    strategies: &[usize],
+    is_dominated: impl Fn(usize, usize) -> bool,
+) -> Option<usize> {
+    strategies.iter().position(|&strategy| {
+        strategies
+            .iter()
+            .any(|&other| other != strategy && is_dominated(strategy, other))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn reduces_to_2x2() {
+        let game = DGame::new(dmatrix![
+            2, -1, 5, 6;
+            -1, 1, 5, 6;
+            1, -2, 4, 5;
+            -2, 0, 3, 2;
+        ]);
+        let reduced = game.reduced_form();
+
+        assert_eq!(reduced.game, DGame::new(dmatrix![2, -1; -1, 1]));
+        assert_eq!(reduced.rows, vec![0, 1]);
+        assert_eq!(reduced.columns, vec![0, 1]);
+        assert_eq!(reduced.saddle_value, None);
+    }
+
+    #[test]
+    fn saddle_point_short_circuits() {
+        let game = DGame::new(dmatrix![
+            3, 1, 2;
+            4, 0, -1;
+            5, 2, 3;
+        ]);
+        let reduced = game.reduced_form();
+
+        assert_eq!(reduced.game, DGame::new(dmatrix![2]));
+        assert_eq!((reduced.rows, reduced.columns), (vec![2], vec![1]));
+        assert_eq!(reduced.saddle_value, Some(2));
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::AddAssign;
+
+use nalgebra::{DVector, RealField, Scalar};
+use rand::{
+    distributions::{uniform::SampleUniform, Distribution, WeightedIndex},
+    Rng,
+};
+
+use super::DGame;
+
+impl<T: Scalar + Copy> DGame<T> {
+    /// Simulates `n` rounds of the game in which the players choose their strategies
+    /// randomly according to the mixed strategies `x` and `y`,
+    /// and returns the realized payoffs.
+    ///
+    /// The mean of the payoffs converges to the expected payoff of the strategies,
+    /// which is the value of the game if they are optimal.
+    ///
+    /// # Panics
+    ///
+    /// If the strategies do not match the dimensions of the game,
+    /// or if any of them has negative probabilities or only zero ones.
+    pub fn sample_plays<W>(
+        &self,
+        x: &DVector<W>,
+        y: &DVector<W>,
+        mut random: impl Rng,
+        n: usize,
+    ) -> Vec<T>
+    where
+        W: SampleUniform + PartialOrd + Default + Clone + for<'a> AddAssign<&'a W>,
+    {
+        let Self(matrix) = self;
+        assert_eq!(
+            x.len(),
+            matrix.nrows(),
+            "x should have a probability per row"
+        );
+        assert_eq!(
+            y.len(),
+            matrix.ncols(),
+            "y should have a probability per column"
+        );
+
+        let rows = WeightedIndex::new(x.iter()).expect("x should be a valid mixed strategy");
+        let columns = WeightedIndex::new(y.iter()).expect("y should be a valid mixed strategy");
+        (0..n)
+            .map(|_| matrix[(rows.sample(&mut random), columns.sample(&mut random))])
+            .collect()
+    }
+}
+
+impl<T: RealField + Copy> DGame<T> {
+    /// Computes the variance of the payoff when the players choose their strategies
+    /// independently according to the mixed strategies `x` and `y`.
+    ///
+    /// Even for the optimal strategies, whose expected payoff is the value of the game,
+    /// this shows how much the outcome of a single play may deviate from it.
+    ///
+    /// # Panics
+    ///
+    /// If the strategies do not match the dimensions of the game.
+    #[must_use]
+    pub fn payoff_variance(&self, x: &DVector<T>, y: &DVector<T>) -> T {
+        let Self(matrix) = self;
+        let mean = (x.transpose() * matrix * y)[(0, 0)];
+        let squares = matrix.map(|payoff| payoff * payoff);
+        let mean_square = (x.transpose() * squares * y)[(0, 0)];
+        mean_square - mean * mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+
+    #[test]
+    fn sample_mean_approaches_value() {
+        // the value of the game is `0.2` and the standard deviation of a single play is `1.2`
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let strategy = dvector![0.4, 0.6];
+
+        let plays = game.sample_plays(&strategy, &strategy, ChaCha20Rng::seed_from_u64(42), 10_000);
+        assert_eq!(plays.len(), 10_000);
+
+        // four standard errors
+        let mean = plays.iter().sum::<f64>() / plays.len() as f64;
+        assert!((mean - 0.2).abs() < 4. * 1.2 / 100., "{mean}");
+    }
+
+    #[test]
+    fn payoff_variance_matches_samples() {
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let strategy = dvector![0.4, 0.6];
+        let variance = game.payoff_variance(&strategy, &strategy);
+        assert!((variance - 1.44).abs() < 1e-9, "{variance}");
+
+        let plays = game.sample_plays(&strategy, &strategy, ChaCha20Rng::seed_from_u64(42), 10_000);
+        let mean = plays.iter().sum::<f64>() / plays.len() as f64;
+        let sample_variance =
+            plays.iter().map(|play| (play - mean).powi(2)).sum::<f64>() / (plays.len() - 1) as f64;
+        assert!(
+            (sample_variance - variance).abs() < 0.1,
+            "{sample_variance}"
+        );
+
+        let pure = dvector![1., 0.];
+        assert_eq!(game.payoff_variance(&pure, &pure), 0.);
+    }
+}
@@ -0,0 +1,232 @@
+use std::num::NonZeroUsize;
+
+use nalgebra::{DVector, Dyn, U1};
+
+use super::{fictitious_play, DGame};
+use crate::simplex;
+
+/// The value of a game with the optimal mixed strategies of players A and B.
+pub type MixedSolution<T> = (T, DVector<T>, DVector<T>);
+
+/// A method of finding the optimal mixed strategies of a zero-sum game.
+pub trait ZeroSumSolver<T> {
+    /// Solves the game producing its value and the optimal mixed strategies of the players.
+    ///
+    /// Returns [`None`] if the game cannot be solved by this method.
+    fn solve(&mut self, game: DGame<T>) -> Option<MixedSolution<T>>;
+}
+
+/// Solver using the [analytical solution](DGame::solve_analytically) of the game.
+///
+/// This only succeeds if all the strategies of both players are used in the equilibrium.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Analytic;
+
+impl ZeroSumSolver<f64> for Analytic {
+    fn solve(&mut self, game: DGame<f64>) -> Option<MixedSolution<f64>> {
+        if !game.0.is_square() {
+            return None;
+        }
+
+        let (x, y) = game.solve_analytically()?;
+        let n = game.0.nrows();
+        let value = y[n];
+        let (x, y) = (
+            x.remove_row(n).reshape_generic(Dyn(n), U1),
+            y.remove_row(n).reshape_generic(Dyn(n), U1),
+        );
+        // the equalizing system may produce improper (negative or infinite) probabilities
+        let is_proper = |strategy: &DVector<f64>| {
+            strategy
+                .iter()
+                .all(|&probability| probability.is_finite() && probability >= 0.)
+        };
+        (value.is_finite() && is_proper(&x) && is_proper(&y)).then_some((value, x, y))
+    }
+}
+
+/// Solver using the [linear programming](DGame::solve_linear_programming) formulation of the game.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LinearProgramming;
+
+impl ZeroSumSolver<f64> for LinearProgramming {
+    fn solve(&mut self, game: DGame<f64>) -> Option<MixedSolution<f64>> {
+        game.solve_linear_programming()
+    }
+}
+
+/// Solver using [fictitious play](fictitious_play) with the fixed number of iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FictitiousPlay {
+    pub iterations: NonZeroUsize,
+}
+
+impl ZeroSumSolver<f64> for FictitiousPlay {
+    fn solve(&mut self, game: DGame<f64>) -> Option<MixedSolution<f64>> {
+        if game.0.is_empty() {
+            return None;
+        }
+
+        Some(fictitious_play(&game, self.iterations))
+    }
+}
+
+impl DGame<f64> {
+    /// Solves the game exactly by reducing it to a [linear program](simplex).
+    ///
+    /// The matrix is shifted to contain only positive values, then player B maximizes `Σy`
+    /// subject to `My <= 1`, so that the value of the game is `1/Σy`,
+    /// and the optimal strategy of player A is given by the dual variables.
+    ///
+    /// Returns [`None`] if the game is empty.
+    #[must_use]
+    pub fn solve_linear_programming(&self) -> Option<MixedSolution<f64>> {
+        let matrix = &self.0;
+        if matrix.is_empty() {
+            return None;
+        }
+
+        let shift = 1. - matrix.min().min(0.);
+        let shifted = matrix.add_scalar(shift);
+        let (rows, columns) = matrix.shape();
+        let simplex::Solution { value, x, dual } = simplex::maximize(
+            &DVector::repeat(columns, 1.),
+            &shifted,
+            &DVector::repeat(rows, 1.),
+        )
+        .expect("the shifted game should always be solvable");
+
+        Some((1. / value - shift, dual / value, x / value))
+    }
+
+    /// Checks numerically that the game satisfies the minimax theorem,
+    /// i.e. `max_x min_y xᵀMy = min_y max_x xᵀMy` within the given tolerance.
+    ///
+    /// Both sides are computed by separate [linear programs](Self::solve_linear_programming),
+    /// the one of player B for the original game and the one of player A for the
+    /// [complementary game](Self::complementary), and are evaluated as the payoffs
+    /// guaranteed by the found strategies against the best responses of the opponent.
+    /// As the theorem always holds, a failure indicates an error of the solver.
+    ///
+    /// The empty game trivially satisfies the theorem.
+    #[must_use]
+    pub fn verify_minimax_theorem(&self, tolerance: f64) -> bool {
+        let (Some((_, _, y)), Some((_, _, x))) = (
+            self.solve_linear_programming(),
+            self.complementary().solve_linear_programming(),
+        ) else {
+            return true;
+        };
+
+        let maximin = (x.transpose() * &self.0).min();
+        let minimax = (&self.0 * y).max();
+        (minimax - maximin).abs() <= tolerance
+    }
+
+    /// Solves the game restricted to the strategies `rows` of player A and `columns` of player B
+    /// assuming that all of them are used in the equilibrium.
+    ///
+    /// The strategies outside of the supports get zero probabilities.
+    /// Returns [`None`] if the supports are of different sizes or empty,
+    /// or if the restricted game has no [proper](Analytic) solution.
+    /// Note that the result is only an equilibrium of the whole game
+    /// if no strategy outside of the supports is a better response.
+    ///
+    /// # Panics
+    ///
+    /// If any of the strategies is out of the game's bounds.
+    #[must_use]
+    pub fn value_on_support(
+        &self,
+        rows: &[usize],
+        columns: &[usize],
+    ) -> Option<MixedSolution<f64>> {
+        if rows.is_empty() || rows.len() != columns.len() {
+            return None;
+        }
+
+        let restricted = self.0.select_rows(rows).select_columns(columns);
+        let (value, support_x, support_y) = Analytic.solve(DGame::new(restricted))?;
+
+        let (mut x, mut y) = (
+            DVector::zeros(self.0.nrows()),
+            DVector::zeros(self.0.ncols()),
+        );
+        for (&row, probability) in rows.iter().zip(support_x.iter()) {
+            x[row] = *probability;
+        }
+        for (&column, probability) in columns.iter().zip(support_y.iter()) {
+            y[column] = *probability;
+        }
+        Some((value, x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::generate::random_matrix;
+
+    #[test]
+    fn minimax_theorem_holds() {
+        let mut random = ChaCha20Rng::seed_from_u64(42);
+        for (rows, columns) in [(1, 1), (2, 2), (3, 5), (6, 4), (10, 10)] {
+            let game = DGame::new(random_matrix(&mut random, rows, columns, -10.0..10.));
+            assert!(game.verify_minimax_theorem(1e-9), "{game}");
+        }
+        assert!(DGame::<f64>::new(dmatrix![]).verify_minimax_theorem(0.));
+    }
+
+    #[test]
+    fn linear_programming_matches_analytic() {
+        let game = DGame::new(dmatrix![
+            2., -1.;
+            -1., 1.;
+        ]);
+        let (value, x, y) = LinearProgramming.solve(game.clone()).unwrap();
+        let (analytic_value, analytic_x, analytic_y) = Analytic.solve(game).unwrap();
+
+        assert!((value - analytic_value).abs() < 1e-9);
+        assert!((x - analytic_x).norm() < 1e-9);
+        assert!((y - analytic_y).norm() < 1e-9);
+    }
+
+    #[test]
+    fn linear_programming_with_dominated_strategies() {
+        // the last row and the last column are dominated, so the analytic solution is improper
+        let game = DGame::new(dmatrix![
+            2., -1., 3.;
+            -1., 1., 2.;
+            -2., -2., 4.;
+        ]);
+        let (value, x, y) = LinearProgramming.solve(game.clone()).unwrap();
+
+        assert!((value - 0.2).abs() < 1e-9);
+        assert!((x - dvector![0.4, 0.6, 0.]).norm() < 1e-9);
+        assert!((y - dvector![0.4, 0.6, 0.]).norm() < 1e-9);
+        assert_eq!(Analytic.solve(game), None);
+    }
+
+    #[test]
+    fn value_on_support() {
+        let game = DGame::new(dmatrix![
+            2., -1., 5.;
+            -1., 1., 5.;
+            -3., -3., 6.;
+        ]);
+
+        let (value, x, y) = game.value_on_support(&[0, 1], &[0, 1]).unwrap();
+        assert!((value - 0.2).abs() < 1e-9);
+        assert!((x - dvector![0.4, 0.6, 0.]).norm() < 1e-9);
+        assert!((y - dvector![0.4, 0.6, 0.]).norm() < 1e-9);
+
+        // player A cannot equalize the columns `0` and `2` with the rows `0` and `1`
+        assert_eq!(game.value_on_support(&[0, 1], &[0, 2]), None);
+        assert_eq!(game.value_on_support(&[0, 1], &[0]), None);
+        assert_eq!(game.value_on_support(&[], &[]), None);
+    }
+}
@@ -0,0 +1,112 @@
+use nalgebra::Scalar;
+
+use super::DGame;
+
+impl<T: Scalar + Copy + PartialOrd> DGame<T> {
+    /// Computes the value realized when the players choose their pure maximin and minimax strategies
+    /// and the ties between them are resolved against player A.
+    ///
+    /// Together with the [optimistic value](Self::optimistic_tiebreak_value)
+    /// this gives the range of the outcomes realizable by the tied pure strategies.
+    /// They coincide if the game has a saddle point.
+    ///
+    /// # Panics
+    ///
+    /// If the game is empty.
+    #[must_use]
+    pub fn adversarial_tiebreak_value(&self) -> T {
+        self.tied_outcomes()
+            .reduce(|worst, outcome| if outcome < worst { outcome } else { worst })
+            .expect("the game should not be empty")
+    }
+
+    /// Computes the value realized when the players choose their pure maximin and minimax strategies
+    /// and the ties between them are resolved in favor of player A.
+    ///
+    /// See [`adversarial_tiebreak_value`](Self::adversarial_tiebreak_value).
+    ///
+    /// # Panics
+    ///
+    /// If the game is empty.
+    #[must_use]
+    pub fn optimistic_tiebreak_value(&self) -> T {
+        self.tied_outcomes()
+            .reduce(|best, outcome| if outcome > best { outcome } else { best })
+            .expect("the game should not be empty")
+    }
+
+    /// Gets the outcomes of all the pairs of the maximin rows and the minimax columns.
+    fn tied_outcomes(&self) -> impl Iterator<Item = T> + '_ {
+        let Self(matrix) = self;
+        let row_minima: Vec<_> = matrix
+            .row_iter()
+            .map(|row| extreme(row.iter(), |new, old| new < old))
+            .collect();
+        let column_maxima: Vec<_> = matrix
+            .column_iter()
+            .map(|column| extreme(column.iter(), |new, old| new > old))
+            .collect();
+        let rows = ties(&row_minima, |new, old| new > old);
+        let columns = ties(&column_maxima, |new, old| new < old);
+
+        rows.into_iter().flat_map(move |row| {
+            columns
+                .clone()
+                .into_iter()
+                .map(move |column| matrix[(row, column)])
+        })
+    }
+}
+
+/// Finds the extreme of the non-empty `values` by `is_better`.
+fn extreme<'a, T: Copy + 'a>(
+    values: impl Iterator<Item = &'a T>,
+    is_better: impl Fn(&T, &T) -> bool,
+) -> T {
+    values
+        .copied()
+        .reduce(|best, value| {
+            if is_better(&value, &best) {
+                value
+            } else {
+                best
+            }
+        })
+        .expect("the game should not be empty")
+}
+
+/// Finds the indices of all the non-empty `values` tied for the extreme by `is_better`.
+fn ties<T: Copy + PartialOrd>(values: &[T], is_better: impl Fn(&T, &T) -> bool) -> Vec<usize> {
+    let best = extreme(values.iter(), is_better);
+    (0..values.len())
+        .filter(|&index| values[index] == best)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn tied_strategies() {
+        let game = DGame::new(dmatrix![
+            2, 0;
+            0, 2;
+        ]);
+        assert_eq!(game.adversarial_tiebreak_value(), 0);
+        assert_eq!(game.optimistic_tiebreak_value(), 2);
+    }
+
+    #[test]
+    fn saddle_point() {
+        let game = DGame::new(dmatrix![
+            3, 1, 2;
+            4, 0, -1;
+            5, 2, 3;
+        ]);
+        assert_eq!(game.adversarial_tiebreak_value(), 2);
+        assert_eq!(game.optimistic_tiebreak_value(), 2);
+    }
+}
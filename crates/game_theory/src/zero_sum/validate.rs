@@ -0,0 +1,71 @@
+use nalgebra::ComplexField;
+
+use super::DGame;
+
+/// A reason for a game to be unsuitable for the solvers.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameValidationIssue {
+    #[error("the matrix is empty")]
+    Empty,
+    #[error("the matrix is not square: {rows}×{columns}")]
+    NonSquare { rows: usize, columns: usize },
+    #[error("the value at ({row}, {column}) is not finite")]
+    NonFinite { row: usize, column: usize },
+}
+
+impl<T: ComplexField> DGame<T> {
+    /// Checks if the game is suitable for the solvers reporting all the found issues at once.
+    ///
+    /// Unlike parsing, this validates the semantics of the game rather than its syntax.
+    pub fn validate(&self) -> Result<(), Vec<GameValidationIssue>> {
+        let matrix = &self.0;
+        let (rows, columns) = matrix.shape();
+
+        let mut issues = Vec::new();
+        if matrix.is_empty() {
+            issues.push(GameValidationIssue::Empty);
+        } else if rows != columns {
+            issues.push(GameValidationIssue::NonSquare { rows, columns });
+        }
+        // `VecStorage` uses column-major order
+        issues.extend(
+            (0..columns)
+                .flat_map(|column| (0..rows).map(move |row| (row, column)))
+                .filter(|&index| !matrix[index].is_finite())
+                .map(|(row, column)| GameValidationIssue::NonFinite { row, column }),
+        );
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, DMatrix};
+
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert_eq!(DGame::new(dmatrix![1., 2.; 3., 4.]).validate(), Ok(()));
+        assert_eq!(
+            DGame::new(DMatrix::<f64>::zeros(0, 0)).validate(),
+            Err(vec![GameValidationIssue::Empty])
+        );
+        assert_eq!(
+            DGame::new(dmatrix![1., f64::INFINITY, 3.; f64::NAN, 5., 6.]).validate(),
+            Err(vec![
+                GameValidationIssue::NonSquare {
+                    rows: 2,
+                    columns: 3
+                },
+                GameValidationIssue::NonFinite { row: 1, column: 0 },
+                GameValidationIssue::NonFinite { row: 0, column: 1 },
+            ])
+        );
+    }
+}
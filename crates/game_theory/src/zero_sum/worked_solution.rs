@@ -0,0 +1,144 @@
+use nalgebra::RealField;
+
+use super::DGame;
+
+/// A single step of a worked solution of a game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionStep<T> {
+    /// Human-readable explanation of the step.
+    pub description: String,
+    /// Named intermediate values computed on this step.
+    pub values: Vec<(&'static str, T)>,
+}
+
+impl<T: RealField + Copy> DGame<T> {
+    /// Solves the `2×2` game step by step explaining each of the steps.
+    ///
+    /// The solution starts by searching for a saddle point
+    /// and, if there is none, proceeds with the oddments of the players' strategies.
+    ///
+    /// # Panics
+    ///
+    /// If the game is not `2×2`.
+    #[must_use]
+    pub fn worked_solution_2x2(&self) -> Vec<SolutionStep<T>> {
+        let matrix = &self.0;
+        assert_eq!(matrix.shape(), (2, 2), "the game should be 2×2");
+        let (a, b, c, d) = (
+            matrix[(0, 0)],
+            matrix[(0, 1)],
+            matrix[(1, 0)],
+            matrix[(1, 1)],
+        );
+
+        let (row, lowest) = self.lowest_price();
+        let (column, highest) = self.highest_price();
+        let mut steps = vec![SolutionStep {
+            description: format!(
+                "The lower value of the game is α={lowest} and the upper value is β={highest}"
+            ),
+            values: vec![("α", lowest), ("β", highest)],
+        }];
+        if lowest == highest {
+            steps.push(SolutionStep {
+                description: format!(
+                    "α=β, so there is a saddle point at ({}, {}) and the game is solved in pure strategies with the value v={lowest}",
+                    row + 1,
+                    column + 1,
+                ),
+                values: vec![("v", lowest)],
+            });
+            return steps;
+        }
+        steps.push(SolutionStep {
+            description:
+                "α<β, so there is no saddle point and the game is solved in mixed strategies"
+                    .to_owned(),
+            values: vec![],
+        });
+
+        // the oddment of a strategy is the difference between the payoffs of the other strategy
+        let (row_1, row_2) = ((c - d).abs(), (a - b).abs());
+        let (column_1, column_2) = ((b - d).abs(), (a - c).abs());
+        steps.push(SolutionStep {
+            description: format!(
+                "The oddments of the strategies of A are ({row_1}, {row_2}) and of B are ({column_1}, {column_2})"
+            ),
+            values: vec![
+                ("oddment A1", row_1),
+                ("oddment A2", row_2),
+                ("oddment B1", column_1),
+                ("oddment B2", column_2),
+            ],
+        });
+
+        let (x_1, x_2) = (row_1 / (row_1 + row_2), row_2 / (row_1 + row_2));
+        let (y_1, y_2) = (
+            column_1 / (column_1 + column_2),
+            column_2 / (column_1 + column_2),
+        );
+        steps.push(SolutionStep {
+            description: format!(
+                "Normalizing the oddments gives the optimal strategies x=({x_1}, {x_2}) and y=({y_1}, {y_2})"
+            ),
+            values: vec![("x1", x_1), ("x2", x_2), ("y1", y_1), ("y2", y_2)],
+        });
+
+        let value = a * x_1 * y_1 + b * x_1 * y_2 + c * x_2 * y_1 + d * x_2 * y_2;
+        steps.push(SolutionStep {
+            description: format!("The value of the game is v=xᵀMy={value}"),
+            values: vec![("v", value)],
+        });
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn mixed_strategies() {
+        let game = DGame::new(dmatrix![
+            2f64, -1.;
+            -1., 1.;
+        ]);
+        let steps = game.worked_solution_2x2();
+
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0].values, vec![("α", -1.), ("β", 1.)]);
+        assert_eq!(
+            steps[2].values,
+            vec![
+                ("oddment A1", 2.),
+                ("oddment A2", 3.),
+                ("oddment B1", 2.),
+                ("oddment B2", 3.),
+            ]
+        );
+        assert_eq!(
+            steps[3].values,
+            vec![("x1", 0.4), ("x2", 0.6), ("y1", 0.4), ("y2", 0.6)]
+        );
+        let [("v", value)] = steps[4].values[..] else {
+            panic!("the last step should contain the value");
+        };
+        assert!((value - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn saddle_point() {
+        let game = DGame::new(dmatrix![
+            3f64, 2.;
+            1., 0.;
+        ]);
+        let steps = game.worked_solution_2x2();
+
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[1].values, vec![("v", 2.)]);
+        assert!(steps[1].description.contains("(1, 2)"));
+    }
+}
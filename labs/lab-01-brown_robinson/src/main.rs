@@ -4,7 +4,7 @@ use clap::Parser;
 use prettytable::{format::consts::FORMAT_BOX_CHARS, row, table};
 
 use brown_robinson_method::{BrownRobinson, BrownRobinsonRow};
-use game_theory::zero_sum::DGame;
+use game_theory::{table::ToTable, zero_sum::DGame};
 
 fn main() {
     let Options {
@@ -15,7 +15,7 @@ fn main() {
 
     let mut game = BrownRobinson::new(game.0);
 
-    println!("Игра: {}", game.game());
+    println!("Игра:\n{}", game.game().to_table());
 
     let (min, max) = game.bounds();
     println!("Нижняя цена игры: {min}, верхняя цена игры: {max}");
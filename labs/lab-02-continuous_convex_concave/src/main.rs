@@ -1,8 +1,10 @@
 use std::num::NonZeroUsize;
 
 use clap::Parser;
-use continuous_convex_concave_method::{ContinuousConvexConcaveGame, GameSolution};
-use tracing::info;
+use continuous_convex_concave_method::{
+    CoCoCoSummary, ContinuousConvexConcaveGame, GameSolution, MAX_ITERATIONS,
+};
+use tracing::{info, warn};
 
 #[derive(thiserror::Error, Debug)]
 enum Error {
@@ -46,11 +48,19 @@ fn main() -> Result<(), Error> {
     let GameSolution { x, y, h } = game.solve_analytically();
     info!("Analytically: H({x:.3}, {y:.3}) = {h:.3}");
 
-    let GameSolution { x, y, h } = game
-        .iter(accuracy, windows)
-        .last()
+    let CoCoCoSummary {
+        solution: GameSolution { x, y, h },
+        iterations,
+        sum_delta,
+        capped,
+    } = game
+        .solve_iteratively(accuracy, windows)
         .ok_or(Error::NoSolution)?;
     info!("Iteratively: H({x:.3}, {y:.3}) = {h:.3}");
+    info!("Iterations: {iterations}; final delta: {sum_delta:.3}");
+    if capped {
+        warn!("The accuracy {accuracy} was not reached in {MAX_ITERATIONS} iterations");
+    }
 
     Ok(())
 }
@@ -1,5 +1,8 @@
 use clap::Parser;
-use game_theory::cooperative::CooperativeGame;
+use game_theory::{
+    cooperative::{CooperativeGame, ShapleyAnalysis},
+    table::ToTable,
+};
 use tracing::{error, info, warn};
 
 fn main() {
@@ -17,6 +20,15 @@ fn main() {
         }
     };
 
+    println!("{}", game.diagnostics_table());
+
+    if !game.is_zero_normalized() {
+        warn!(
+            "The value of the empty coalition is not zero: v(0)={}",
+            game.v_empty()
+        );
+    }
+
     if game.is_super_additive() {
         info!("The game is super-additive");
     } else {
@@ -29,24 +41,31 @@ fn main() {
         info!("The game is NOT convex")
     }
 
-    let x: Vec<_> = game.x().collect();
+    let analysis = game.analyze_shapley();
+    println!("{}", analysis.to_table());
+    let ShapleyAnalysis {
+        shapley_value: x,
+        group_rationality_residual,
+        individually_rational,
+    } = analysis;
     info!("Shapley value: {x:.03?}");
 
-    let sum: f64 = x.iter().sum();
     let v_i = *game.v_i();
-    if sum as u8 == v_i {
+    let sum = v_i + group_rationality_residual;
+    if group_rationality_residual.abs() <= TOLERANCE {
         info!("Group rationalism: V(I)={v_i} == sum={sum}")
     } else {
         warn!("NO Group rationalism: V(I)={v_i} != sum={sum}")
     }
 
-    for (i, x, v) in x
+    for (i, x, v, rational) in x
         .iter()
         .zip(game.singular_coalitions())
+        .zip(individually_rational)
         .enumerate()
-        .map(|(index, (&x, i))| (index + 1, x, *game.v(i) as f64))
+        .map(|(index, ((&x, i), rational))| (index + 1, x, *game.v(i), rational))
     {
-        if x >= v {
+        if rational {
             info!("Player {i} Individual rationalism: x_{i}={x:.03} >= v({{{i}}})={v:.03}");
         } else {
             warn!("Player {i} NO Individual rationalism: x_{i}={x:.03} < v({{{i}}})={v:.03}");
@@ -54,6 +73,8 @@ fn main() {
     }
 }
 
+const TOLERANCE: f64 = 1e-9;
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Options {
@@ -62,6 +83,6 @@ struct Options {
     /// # Examples
     ///
     /// `0 1 1 3 1 3 3 4` for the example task.
-    #[clap(default_values_t = vec![0, 1, 1, 2, 1, 2, 3, 6, 4, 7, 7, 10, 7, 10, 10, 12])]
-    characteristic_function: Vec<u8>,
+    #[clap(default_values_t = vec![0., 1., 1., 2., 1., 2., 3., 6., 4., 7., 7., 10., 7., 10., 10., 12.])]
+    characteristic_function: Vec<f64>,
 }
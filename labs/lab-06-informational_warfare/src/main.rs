@@ -1,11 +1,14 @@
 use std::{num::NonZeroU64, ops::DivAssign};
 
 use clap::Parser;
-use game_theory::generate::{random_matrix, random_vector};
-use nalgebra::{DMatrix, DVector};
+use game_theory::{
+    generate::{random_matrix, random_vector},
+    social::{simulate, Consensus},
+};
+use nalgebra::DVector;
 use rand::prelude::*;
 use rand_chacha::ChaCha20Rng;
-use tracing::{debug, error, info};
+use tracing::{error, info};
 
 fn main() {
     let Options {
@@ -53,7 +56,11 @@ fn main() {
 
     let x = random_x(&mut random, dimensions, x_min, x_max);
     info!("x(0) = {:.03}", x.transpose());
-    let (iteration, result_x) = simulate(&a, x.clone(), epsilon);
+    let Consensus {
+        iterations: iteration,
+        opinions: result_x,
+        ..
+    } = simulate(&a, x.clone(), epsilon);
     info!("x({iteration}) = {:.03}", result_x.transpose());
     info!("A^{iteration} = {:.03}", a.pow(iteration as u32));
 
@@ -89,7 +96,11 @@ fn main() {
     }
 
     info!("x(0) = {:.03}", x.transpose());
-    let (iteration, result_x) = simulate(&a, x, epsilon);
+    let Consensus {
+        iterations: iteration,
+        opinions: result_x,
+        ..
+    } = simulate(&a, x, epsilon);
     info!("x({iteration}) = {:.03}", result_x.transpose());
     info!("A^{iteration} = {:.03}", a.pow(iteration as u32));
 }
@@ -99,16 +110,6 @@ fn random_x(random: impl Rng, n: usize, min: u64, max: NonZeroU64) -> DVector<f6
     random_vector(random, n, min..=max.get(), |value| value as f64)
 }
 
-fn simulate(a: &DMatrix<f64>, mut x: DVector<f64>, epsilon: f64) -> (usize, DVector<f64>) {
-    let mut iteration = 0;
-    while x.max() - x.min() > epsilon {
-        iteration += 1;
-        x = a * &x;
-        debug!("x({iteration}) = {}", x.transpose());
-    }
-    (iteration, x)
-}
-
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 #[clap(allow_negative_numbers = true)]
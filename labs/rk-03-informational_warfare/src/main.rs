@@ -1,7 +1,10 @@
 use std::{num::NonZeroU64, ops::DivAssign};
 
 use clap::Parser;
-use game_theory::generate::random_matrix;
+use game_theory::{
+    generate::random_matrix,
+    social::{influence_equilibrium, warfare_outcome, WarfareOutcome},
+};
 use nalgebra::DMatrix;
 use rand::prelude::*;
 use rand_chacha::ChaCha20Rng;
@@ -100,8 +103,7 @@ fn main() {
         .sum();
     info!("r_f = {r_f:.03}, r_s = {r_s:.03}");
 
-    let u = u(param_a, param_b, param_c, param_d, g_f, g_s, r_f, r_s);
-    let v = v(param_a, param_b, g_f, r_f, r_s, u);
+    let (u, v) = influence_equilibrium(r_f, r_s, param_a, param_b, param_c, param_d, g_f, g_s);
     info!("u = {u:.03}, v = {v:.03}");
 
     let x = u * r_f + v * r_s;
@@ -115,12 +117,10 @@ fn main() {
     let d_s = (x - max_s).abs();
     info!("d_f = {d_f:.03}, d_s = {d_s:.03}");
 
-    if d_f < d_s  {
-        info!("df < ds => player 1 wins");
-    } else if d_f > d_s {
-        info!("df > ds => player 2 wins");
-    } else {
-        info!("df == ds => draw");
+    match warfare_outcome(x, max_f, max_s) {
+        WarfareOutcome::FirstPlayerWins => info!("df < ds => player 1 wins"),
+        WarfareOutcome::SecondPlayerWins => info!("df > ds => player 2 wins"),
+        WarfareOutcome::Draw => info!("df == ds => draw"),
     }
 }
 
@@ -135,15 +135,6 @@ fn simulate(mut a: DMatrix<f64>, epsilon: f64) -> (usize, DMatrix<f64>) {
     (iteration, a)
 }
 
-fn u(a: f64, b: f64, c: f64, d: f64, g_f: f64, g_s: f64, r_f: f64, r_s: f64) -> f64 {
-    (2. * (a * d - b * c) * r_f * r_s * r_s + a * g_s * r_f)
-        / (2. * d * g_f * r_s * r_s + g_f * g_s + 2. * b * g_s * r_f * r_f)
-}
-
-fn v(a: f64, b: f64, g_f: f64, r_f: f64, r_s: f64, u: f64) -> f64 {
-    (g_f * u + 2. * b * r_f * r_f * u - a * r_f) / (-2. * b * r_f * r_s)
-}
-
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 #[clap(allow_negative_numbers = true)]